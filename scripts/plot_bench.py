@@ -0,0 +1,27 @@
+# Regenerates Engines_performance.png from `cli_search bench --format csv`:
+#   cargo run --release -- bench --format csv > bench.csv
+#   python3 scripts/plot_bench.py bench.csv Engines_performance.png
+import csv
+import sys
+from collections import defaultdict
+
+import matplotlib.pyplot as plt
+
+rows = csv.DictReader(open(sys.argv[1]))
+times = defaultdict(lambda: defaultdict(list))
+for row in rows:
+    engine = row["engine"] + (f" ({row['threads']} threads)" if row["threads"] else "")
+    times[engine][row["phase"]].append(int(row["mean_ns"]) / 1e6)
+
+engines = list(times)
+width = 0.4
+for i, phase in enumerate(["query", "options"]):
+    means = [sum(times[e][phase]) / len(times[e][phase]) for e in engines]
+    plt.bar([x + i * width for x in range(len(engines))], means, width, label=phase.capitalize())
+
+plt.xticks([x + width / 2 for x in range(len(engines))], engines, rotation=20, ha="right")
+plt.ylabel("Average time per keystroke (ms)")
+plt.title("Engines performance")
+plt.legend()
+plt.tight_layout()
+plt.savefig(sys.argv[2] if len(sys.argv) > 2 else "Engines_performance.png")
@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use cli_search::{Error, Result};
+
+// command line arguments: a subcommand followed by `--flag` and `--name value` options
+pub struct Args {
+    args: Vec<String>,
+}
+
+impl Args {
+    pub fn from_env() -> Self {
+        Self { args: std::env::args().skip(1).collect() }
+    }
+
+    pub fn command(&self) -> Option<&str> {
+        self.args.first().map(String::as_str).filter(|a| !a.starts_with("--"))
+    }
+
+    // the first argument after the subcommand which is neither an option nor the value of one, e.g. the query of
+    // `filter --limit 2 QUERY`. every option not named in `flags` takes the argument after it unless given as
+    // `--name=value`
+    pub fn operand(&self, flags: &[&str]) -> Option<&str> {
+        self.command()?;
+        let mut rest = self.args[1..].iter();
+        while let Some(a) = rest.next() {
+            match a.strip_prefix("--") {
+                Some(name) if !name.contains('=') && !flags.contains(&name) => {
+                    rest.next();
+                }
+                Some(_) => {}
+                None => return Some(a),
+            }
+        }
+        None
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.args.iter().any(|a| a.strip_prefix("--") == Some(name))
+    }
+
+    // the value following `--name`, or given as `--name=value`
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.args.iter().enumerate().find_map(|(i, a)| {
+            let rest = a.strip_prefix("--")?.strip_prefix(name)?;
+            match rest.strip_prefix('=') {
+                Some(v) => Some(v),
+                None if rest.is_empty() => self.args.get(i + 1).map(String::as_str),
+                None => None,
+            }
+        })
+    }
+
+    pub fn parse<T: FromStr>(&self, name: &'static str, default: T) -> Result<T> {
+        match self.value(name) {
+            Some(v) => v.parse().map_err(|_| Error::InvalidValue { what: name, value: v.to_owned() }),
+            None => Ok(default),
+        }
+    }
+
+    // a comma separated list, e.g. `--threads 1,2,4`
+    pub fn list<T: FromStr>(&self, name: &'static str, default: Vec<T>) -> Result<Vec<T>> {
+        match self.value(name) {
+            Some(v) => v.split(',').map(|i| i.trim().parse().map_err(|_| Error::InvalidValue { what: name, value: i.to_owned() })).collect(),
+            None => Ok(default),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::time::Instant;
+
+use cli_search::SearchEngine;
+use serde_json::{json, Value};
+
+// percentiles and mean of a set of timings, in nanoseconds
+#[derive(Debug, Clone, Copy)]
+pub struct Summary {
+    pub p50: u128,
+    pub p95: u128,
+    pub p99: u128,
+    pub mean: u128,
+}
+
+impl Summary {
+    fn new(mut samples: Vec<u128>) -> Self {
+        samples.sort_unstable();
+        // nearest rank, the smallest sample which at least `p` percent of the samples are below or equal to
+        let rank = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+
+        Self {
+            p50: rank(50),
+            p95: rank(95),
+            p99: rank(99),
+            mean: samples.iter().sum::<u128>() / samples.len() as u128,
+        }
+    }
+}
+
+// timings of one keystroke of the query over all the measured runs
+#[derive(Debug, Clone)]
+pub struct Keystroke {
+    pub key: char,
+    pub query: Summary,
+    pub options: Summary,
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchResult {
+    pub engine: String,
+    pub threads: Option<usize>,
+    pub keystrokes: Vec<Keystroke>,
+}
+
+// types `query` into a new engine `warmup + runs` times and summarises the `query` and `options` timings of
+// each keystroke over the last `runs`. each run gets its own engine, one engine would bring back the options
+// it kept from the run before
+pub fn bench<E: SearchEngine>(new_engine: impl Fn() -> E, query: &str, warmup: usize, runs: usize) -> Vec<Keystroke> {
+    let keys: Vec<char> = query.chars().collect();
+    let mut query_times = vec![Vec::with_capacity(runs); keys.len()];
+    let mut opt_times = vec![Vec::with_capacity(runs); keys.len()];
+
+    for run in 0..warmup + runs {
+        let mut engine = new_engine();
+        for (i, c) in keys.iter().enumerate() {
+            let now1 = Instant::now();
+            engine.query(*c);
+            let query_time = now1.elapsed().as_nanos();
+
+            let now2 = Instant::now();
+            engine.options();
+            let opt_time = now2.elapsed().as_nanos();
+
+            if run >= warmup {
+                query_times[i].push(query_time);
+                opt_times[i].push(opt_time);
+            }
+        }
+    }
+
+    keys.into_iter()
+        .zip(query_times.into_iter().zip(opt_times))
+        .map(|(key, (q, o))| Keystroke { key, query: Summary::new(q), options: Summary::new(o) })
+        .collect()
+}
+
+fn label(result: &BenchResult) -> String {
+    match result.threads {
+        Some(threads) => format!("{} ({} threads)", result.engine, threads),
+        None => result.engine.clone(),
+    }
+}
+
+fn micros(ns: u128) -> String {
+    format!("{:.1}", ns as f64 / 1000.0)
+}
+
+pub fn to_table(results: &[BenchResult]) -> String {
+    let mut out = String::new();
+
+    for result in results {
+        out.push_str(&format!("\n{} performance (us):\n", label(result)));
+        out.push_str("key   query p50   p95   p99  mean | options p50   p95   p99  mean\n");
+
+        for k in &result.keystrokes {
+            out.push_str(&format!(
+                "{:<3} {:>9} {:>5} {:>5} {:>5} | {:>11} {:>5} {:>5} {:>5}\n",
+                k.key,
+                micros(k.query.p50), micros(k.query.p95), micros(k.query.p99), micros(k.query.mean),
+                micros(k.options.p50), micros(k.options.p95), micros(k.options.p99), micros(k.options.mean),
+            ));
+        }
+    }
+
+    out
+}
+
+// quoted when it holds a comma, a quote or a line break, with its quotes doubled
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+// one row per engine, keystroke and phase, the mean_ns column averaged per engine and phase gives the chart in the README
+pub fn to_csv(results: &[BenchResult]) -> String {
+    let mut out = String::from("engine,threads,keystroke,key,phase,p50_ns,p95_ns,p99_ns,mean_ns\n");
+
+    for result in results {
+        for (i, k) in result.keystrokes.iter().enumerate() {
+            for (phase, s) in [("query", k.query), ("options", k.options)] {
+                out.push_str(&format!(
+                    "{},{},{},{},{},{},{},{},{}\n",
+                    csv_field(&result.engine),
+                    result.threads.map_or(String::new(), |t| t.to_string()),
+                    i, csv_field(&k.key.to_string()), phase, s.p50, s.p95, s.p99, s.mean,
+                ));
+            }
+        }
+    }
+
+    out
+}
+
+fn summary_json(s: &Summary) -> Value {
+    json!({ "p50_ns": s.p50, "p95_ns": s.p95, "p99_ns": s.p99, "mean_ns": s.mean })
+}
+
+pub fn to_json(results: &[BenchResult]) -> String {
+    let results: Vec<Value> = results.iter().map(|result| json!({
+        "engine": result.engine,
+        "threads": result.threads,
+        "keystrokes": result.keystrokes.iter().map(|k| json!({
+            "key": k.key.to_string(),
+            "query": summary_json(&k.query),
+            "options": summary_json(&k.options),
+        })).collect::<Vec<_>>(),
+    })).collect();
+
+    format!("{}\n", Value::Array(results))
+}
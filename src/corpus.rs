@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::Error;
+
+// how the lengths of the generated lines are picked
+#[derive(Debug, Clone, Copy)]
+pub enum LengthDist {
+  Fixed(usize),
+  // between `min` and `max`, both included
+  Uniform { min: usize, max: usize },
+  // rounded and clamped between 1 and `MAX_LENGTH`
+  Normal { mean: f64, std_dev: f64 },
+}
+
+// the longest line generated, a line is built in memory before it's given out
+pub const MAX_LENGTH: usize = 1 << 24;
+
+impl LengthDist {
+  // whether lines can be generated with the lengths: none longer than `MAX_LENGTH`, a normal one finite
+  // (a sample beyond `MAX_LENGTH` is clamped to it)
+  pub fn is_valid(&self) -> bool {
+    match *self {
+      LengthDist::Fixed(len) => len <= MAX_LENGTH,
+      LengthDist::Uniform { min, max } => min <= MAX_LENGTH && max <= MAX_LENGTH,
+      LengthDist::Normal { mean, std_dev } => mean.abs() <= MAX_LENGTH as f64 && std_dev.abs() <= MAX_LENGTH as f64,
+    }
+  }
+}
+
+// `N` for a fixed length, `MIN..MAX` for a uniform one and `MEAN~STD_DEV` for a normal one
+impl FromStr for LengthDist {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || Error::InvalidValue { what: "length distribution", value: s.to_owned() };
+
+    let length = if let Some((min, max)) = s.split_once("..") {
+      LengthDist::Uniform { min: min.parse().map_err(|_| invalid())?, max: max.parse().map_err(|_| invalid())? }
+    } else if let Some((mean, std_dev)) = s.split_once('~') {
+      LengthDist::Normal { mean: mean.parse().map_err(|_| invalid())?, std_dev: std_dev.parse().map_err(|_| invalid())? }
+    } else {
+      LengthDist::Fixed(s.parse().map_err(|_| invalid())?)
+    };
+    if length.is_valid() { Ok(length) } else { Err(invalid()) }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct CorpusConfig {
+  pub alphabet: Vec<char>,
+  pub count: usize,
+  pub length: LengthDist,
+  // the share of lines starting with a prefix of an earlier line, so the trie has branches to share
+  pub shared_prefix: f64,
+  pub seed: u64,
+}
+
+impl Default for CorpusConfig {
+  // the shape of the original sample: 1007 lines of 10 to 99 chars between 'A' and 'O'
+  fn default() -> Self {
+    Self {
+      alphabet: ('A'..='O').collect(),
+      count: 1007,
+      length: LengthDist::Uniform { min: 10, max: 99 },
+      shared_prefix: 0.0,
+      seed: 0,
+    }
+  }
+}
+
+// the earlier lines kept to take the shared prefixes from, so the memory used doesn't grow with `count`
+const PREFIX_POOL: usize = 1024;
+
+// generates the lines of a corpus one at a time, the same config always gives the same lines
+// (as long as the `rand` version, and so the `StdRng` algorithm, doesn't change)
+pub struct Corpus {
+  config: CorpusConfig,
+  rng: StdRng,
+  generated: usize,
+  pool: Vec<Vec<char>>,
+}
+
+impl Corpus {
+  // panics when the config can't generate lines, see `try_new`
+  pub fn new(config: CorpusConfig) -> Self {
+    Self::try_new(config).unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  // fails on an empty alphabet, a shared prefix share outside 0..=1 (or NaN) and invalid lengths,
+  // see `LengthDist::is_valid`
+  pub fn try_new(config: CorpusConfig) -> Result<Self, Error> {
+    if config.alphabet.is_empty() {
+      return Err(Error::InvalidValue { what: "alphabet", value: String::new() });
+    }
+    if !(0.0..=1.0).contains(&config.shared_prefix) {
+      return Err(Error::InvalidValue { what: "shared prefix", value: config.shared_prefix.to_string() });
+    }
+    if !config.length.is_valid() {
+      return Err(Error::InvalidValue { what: "length distribution", value: format!("{:?}", config.length) });
+    }
+
+    Ok(Self { rng: StdRng::seed_from_u64(config.seed), config, generated: 0, pool: Vec::new() })
+  }
+
+  fn length(&mut self) -> usize {
+    match self.config.length {
+      LengthDist::Fixed(len) => len,
+      LengthDist::Uniform { min, max } => self.rng.random_range(min..=max.max(min)),
+      LengthDist::Normal { mean, std_dev } => {
+        // Box-Muller transform
+        let (u1, u2): (f64, f64) = (1.0 - self.rng.random::<f64>(), self.rng.random());
+        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        (mean + z * std_dev).round().clamp(1.0, MAX_LENGTH as f64) as usize
+      }
+    }
+  }
+}
+
+impl Iterator for Corpus {
+  type Item = String;
+
+  fn next(&mut self) -> Option<String> {
+    if self.generated == self.config.count {
+      return None;
+    }
+    self.generated += 1;
+
+    let len = self.length();
+    let mut line = Vec::with_capacity(len);
+
+    if !self.pool.is_empty() && self.rng.random_bool(self.config.shared_prefix) {
+      let source = &self.pool[self.rng.random_range(0..self.pool.len())];
+      let shared = self.rng.random_range(1..=source.len().min(len).max(1));
+      line.extend_from_slice(&source[..shared.min(source.len())]);
+    }
+
+    while line.len() < len {
+      line.push(self.config.alphabet[self.rng.random_range(0..self.config.alphabet.len())]);
+    }
+
+    if self.pool.len() < PREFIX_POOL {
+      self.pool.push(line.clone());
+    } else {
+      let i = self.rng.random_range(0..PREFIX_POOL);
+      self.pool[i] = line.clone();
+    }
+
+    Some(line.into_iter().collect())
+  }
+}
@@ -0,0 +1,285 @@
+use std::{
+  collections::HashMap,
+  fs,
+  io::{self, BufRead, BufReader, Write},
+  os::unix::{fs::FileTypeExt, net::{UnixListener, UnixStream}},
+  path::Path,
+  sync::{atomic::{AtomicU64, Ordering}, Arc, Mutex},
+  thread,
+};
+
+use serde_json::{json, Value};
+
+use crate::{Alignment, Case, LiveEngine, Match, OwnedEngine, PathMode, SearchConfig, SearchEngine, SharedTrie};
+
+// one search in progress: the engine keeps the nodes reached by each symbol of the query,
+// so a query extending the last one only walks the trie for the new symbols
+struct Session {
+  engine: LiveEngine<OwnedEngine>,
+  query: Vec<char>,
+}
+
+// named tries kept in memory and the sessions searching them, shared by every connection.
+//
+// the protocol is one JSON object per line each way, every request has a `cmd` and gets back `ok` with either
+// the result or an `error`:
+//   {"cmd": "load", "trie": "files", "lines": ["..."]} or "path": "FILE"  -> {"ok": true, "count": 2}
+//   {"cmd": "insert", "trie": "files", "lines": ["..."]}                  -> {"ok": true, "ids": [2]}
+//   {"cmd": "remove", "trie": "files", "lines": ["..."]}                  -> {"ok": true, "removed": 1}
+//   {"cmd": "open", "trie": "files", "acronym": false, "smart_case": false, "path": false, "require_basename": false,
+//    "greedy": false, "order": "score"}                                   -> {"ok": true, "session": 0}
+//   {"cmd": "query", "session": 0, "query": "src"}                        -> {"ok": true, "count": 12}
+//   {"cmd": "options", "session": 0, "limit": 10}
+//     -> {"ok": true, "count": 12, "options": [{"line", "display", "positions", "score", "id"}]}
+//   {"cmd": "close", "session": 0}                                        -> {"ok": true}
+//
+// a session can be used from any connection, it's closed with the connection which opened it at the latest
+#[derive(Default)]
+pub struct Daemon {
+  tries: Mutex<HashMap<String, Arc<SharedTrie>>>,
+  // each session is locked on its own so a slow query doesn't hold up the others
+  sessions: Mutex<HashMap<u64, Arc<Mutex<Session>>>>,
+  next_session: AtomicU64,
+}
+
+fn field<'a>(request: &'a Value, name: &str) -> Result<&'a Value, String> {
+  request.get(name).ok_or_else(|| format!("missing field: {}", name))
+}
+
+fn str_field<'a>(request: &'a Value, name: &str) -> Result<&'a str, String> {
+  field(request, name)?.as_str().ok_or_else(|| format!("{} must be a string", name))
+}
+
+fn lines_field(request: &Value) -> Result<Vec<&str>, String> {
+  field(request, "lines")?
+    .as_array()
+    .and_then(|lines| lines.iter().map(Value::as_str).collect())
+    .ok_or_else(|| "lines must be an array of strings".to_owned())
+}
+
+fn flag(request: &Value, name: &str) -> bool {
+  request.get(name).and_then(Value::as_bool).unwrap_or(false)
+}
+
+// a ranked match as the daemon and the HTTP API list it
+pub(crate) fn option_json(m: &Match, config: &SearchConfig) -> Value {
+  json!({
+    "line": m.line(),
+    "display": m.display(config),
+    "positions": m.positions(),
+    "score": m.score(),
+    "id": u64::from(m.id()),
+  })
+}
+
+impl Daemon {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  // answers one request, errors are reported in the response so the connection stays usable
+  pub fn handle(&self, request: &Value) -> Value {
+    let result = match request.get("cmd").and_then(Value::as_str) {
+      Some("load") => self.load(request),
+      Some("insert") => self.insert(request),
+      Some("remove") => self.remove(request),
+      Some("open") => self.open(request),
+      Some("query") => self.query(request),
+      Some("options") => self.options(request),
+      Some("close") => self.close(request),
+      Some(cmd) => Err(format!("unknown cmd: {}", cmd)),
+      None => Err("missing field: cmd".to_owned()),
+    };
+
+    match result {
+      Ok(mut response) => {
+        response["ok"] = json!(true);
+        response
+      }
+      Err(error) => json!({ "ok": false, "error": error }),
+    }
+  }
+
+  fn trie(&self, request: &Value) -> Result<Arc<SharedTrie>, String> {
+    let name = str_field(request, "trie")?;
+    self.tries.lock().unwrap().get(name).cloned().ok_or_else(|| format!("unknown trie: {}", name))
+  }
+
+  fn session(&self, request: &Value) -> Result<Arc<Mutex<Session>>, String> {
+    let id = field(request, "session")?.as_u64().ok_or("session must be a number")?;
+    self.sessions.lock().unwrap().get(&id).cloned().ok_or_else(|| format!("unknown session: {}", id))
+  }
+
+  // replaces the trie with the given name, the sessions on the old one keep searching it until they are closed
+  fn load(&self, request: &Value) -> Result<Value, String> {
+    let name = str_field(request, "trie")?;
+    let trie = SharedTrie::new();
+    match request.get("path").and_then(Value::as_str) {
+      Some(path) => {
+        let file = fs::File::open(path).map_err(|e| format!("{}: {}", path, e))?;
+        trie.read_lines(BufReader::new(file)).map_err(|e| format!("{}: {}", path, e))?;
+      }
+      None => trie.extend(lines_field(request)?),
+    }
+
+    let count = trie.len();
+    self.tries.lock().unwrap().insert(name.to_owned(), Arc::new(trie));
+    Ok(json!({ "count": count }))
+  }
+
+  fn insert(&self, request: &Value) -> Result<Value, String> {
+    let trie = self.trie(request)?;
+    let ids: Vec<u64> = lines_field(request)?.into_iter().map(|line| trie.insert(line).into()).collect();
+    Ok(json!({ "ids": ids }))
+  }
+
+  fn remove(&self, request: &Value) -> Result<Value, String> {
+    let trie = self.trie(request)?;
+    let removed = lines_field(request)?.into_iter().filter(|line| trie.remove(line).is_some()).count();
+    Ok(json!({ "removed": removed }))
+  }
+
+  fn open(&self, request: &Value) -> Result<Value, String> {
+    let trie = self.trie(request)?;
+    let order = match request.get("order").and_then(Value::as_str) {
+      Some(order) => order.parse().map_err(|e: crate::Error| e.to_string())?,
+      None => Default::default(),
+    };
+    let config = SearchConfig::default()
+      .with_acronym(flag(request, "acronym"))
+      .with_case(if flag(request, "smart_case") { Case::Smart } else { Case::Sensitive })
+      .with_alignment(if flag(request, "greedy") { Alignment::Greedy } else { Alignment::Optimal })
+      .with_order(order);
+    let require_basename = flag(request, "require_basename");
+    let config = if flag(request, "path") || require_basename {
+      config.with_path(PathMode::default().with_require_basename(require_basename))
+    } else {
+      config
+    };
+
+    let id = self.next_session.fetch_add(1, Ordering::Relaxed);
+
+    let session = Session { engine: trie.engine().with_config(config), query: Vec::new() };
+    self.sessions.lock().unwrap().insert(id, Arc::new(Mutex::new(session)));
+    Ok(json!({ "session": id }))
+  }
+
+  // sets the whole query, erasing back to the part shared with the last one and typing the rest.
+  // the session picks up the lines inserted and removed since, even when the query is the same
+  fn query(&self, request: &Value) -> Result<Value, String> {
+    let session = self.session(request)?;
+    let query: Vec<char> = str_field(request, "query")?.chars().collect();
+    let mut session = session.lock().unwrap();
+
+    session.engine.refresh().map_err(|e| e.to_string())?;
+    // on an error the session keeps the part of the query typed so far
+    let shared = session.query.iter().zip(&query).take_while(|(a, b)| a == b).count();
+    while session.query.len() > shared {
+      session.engine.try_backspace().map_err(|e| e.to_string())?;
+      session.query.pop();
+    }
+    for c in &query[shared..] {
+      session.engine.try_query(*c).map_err(|e| e.to_string())?;
+      session.query.push(*c);
+    }
+
+    Ok(json!({ "count": session.engine.match_count() }))
+  }
+
+  fn options(&self, request: &Value) -> Result<Value, String> {
+    let session = self.session(request)?;
+    let limit = request.get("limit").and_then(Value::as_u64).map_or(usize::MAX, |limit| limit as usize);
+    let session = session.lock().unwrap();
+
+    let config = session.engine.config();
+    let ranked = session.engine.try_ranked().map_err(|e| e.to_string())?;
+    let options: Vec<Value> = ranked.iter().take(limit).map(|m| option_json(m, config)).collect();
+
+    Ok(json!({ "count": ranked.len(), "options": options }))
+  }
+
+  fn close(&self, request: &Value) -> Result<Value, String> {
+    let id = field(request, "session")?.as_u64().ok_or("session must be a number")?;
+    match self.sessions.lock().unwrap().remove(&id) {
+      Some(_) => Ok(json!({})),
+      None => Err(format!("unknown session: {}", id)),
+    }
+  }
+
+  // answers the requests of one connection until it is closed, then closes the sessions it opened and left open,
+  // e.g. when the client crashed, so their engines and snapshots aren't kept for good
+  pub fn handle_connection(&self, stream: UnixStream) -> io::Result<()> {
+    let mut opened = Vec::new();
+    let result = self.answer(stream, &mut opened);
+
+    let mut sessions = self.sessions.lock().unwrap();
+    for id in opened {
+      sessions.remove(&id);
+    }
+    result
+  }
+
+  // the lines are read as bytes, a line which isn't valid UTF-8 gets an error like any invalid JSON
+  fn answer(&self, stream: UnixStream, opened: &mut Vec<u64>) -> io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+    let mut line = Vec::new();
+
+    while reader.read_until(b'\n', &mut line)? > 0 {
+      if !line.trim_ascii().is_empty() {
+        let response = match serde_json::from_slice::<Value>(&line) {
+          Ok(request) => self.handle(&request),
+          Err(e) => json!({ "ok": false, "error": format!("invalid JSON: {}", e) }),
+        };
+        // only `open` answers with a session
+        if let Some(id) = response.get("session").and_then(Value::as_u64) {
+          opened.push(id);
+        }
+        writeln!(writer, "{}", response)?;
+      }
+      line.clear();
+    }
+
+    Ok(())
+  }
+
+  // listens on the socket at `path`, a thread per connection. a socket left over by a daemon which is gone is
+  // replaced, a daemon still listening on it is an error and so is anything else at `path` (binding fails)
+  pub fn serve(self: Arc<Self>, path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref();
+    if fs::symlink_metadata(path).is_ok_and(|meta| meta.file_type().is_socket()) {
+      if UnixStream::connect(path).is_ok() {
+        return Err(io::Error::new(io::ErrorKind::AddrInUse, format!("a daemon is already listening on {}", path.display())));
+      }
+      fs::remove_file(path)?;
+    }
+
+    for stream in UnixListener::bind(path)?.incoming() {
+      let (daemon, stream) = (self.clone(), stream?);
+      thread::spawn(move || daemon.handle_connection(stream));
+    }
+
+    Ok(())
+  }
+}
+
+// a connection to a daemon, for scripts and tests
+pub struct Client {
+  writer: UnixStream,
+  reader: BufReader<UnixStream>,
+}
+
+impl Client {
+  pub fn connect(path: impl AsRef<Path>) -> io::Result<Self> {
+    let writer = UnixStream::connect(path)?;
+    Ok(Self { reader: BufReader::new(writer.try_clone()?), writer })
+  }
+
+  pub fn request(&mut self, request: &Value) -> io::Result<Value> {
+    writeln!(self.writer, "{}", request)?;
+
+    let mut line = String::new();
+    self.reader.read_line(&mut line)?;
+    serde_json::from_str(&line).map_err(io::Error::other)
+  }
+}
@@ -0,0 +1,41 @@
+use std::{fmt, io};
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+  // a setting given as text which doesn't parse, e.g. an unknown order
+  InvalidValue { what: &'static str, value: String },
+  // reading the lines or serving them failed
+  Io(io::Error),
+  // the search of a `TEngine` or `TPEngine` panicked (with the panic message), in a worker thread or while filtering
+  // the lines, or a worker stopped without giving back its part of the search. the engine is left as it was before
+  // the failed call
+  Worker(String)
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Error::InvalidValue { what, value } => write!(f, "invalid {}: {}", what, value),
+      Error::Io(e) => write!(f, "{}", e),
+      Error::Worker(reason) => write!(f, "search worker failed: {}", reason)
+    }
+  }
+}
+
+impl std::error::Error for Error {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Error::Io(e) => Some(e),
+      _ => None
+    }
+  }
+}
+
+impl From<io::Error> for Error {
+  fn from(e: io::Error) -> Self {
+    Error::Io(e)
+  }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
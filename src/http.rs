@@ -0,0 +1,163 @@
+use std::{
+  io,
+  net::{SocketAddr, ToSocketAddrs},
+  sync::Arc,
+};
+
+use serde_json::{json, Value};
+use threadpool::ThreadPool;
+use tiny_http::{Header, Response, Server};
+
+use crate::{daemon::option_json, SearchConfig, SearchEngine, SharedTrie};
+
+// how many options `/search` lists without a `limit`
+const LIMIT: usize = 20;
+
+// a JSON API over one trie, for dashboards and anything else which speaks HTTP:
+//   GET  /search?q=src&limit=20               -> {"count": 12, "options": [{"line", "display", "positions", "score", "id"}]}
+//   POST /insert  {"lines": ["..."]}          -> {"ids": [2]}
+//   POST /remove  {"lines": ["..."]}          -> {"removed": 1}
+//   GET  /stats                               -> {"lines": 1007, "version": 1007}
+// errors get a 4xx status (500 when the search failed) and {"error": "..."}. each search types the query into a new engine on the latest
+// snapshot of the trie, so searches never wait for each other and inserts only wait for the snapshot to be taken
+pub struct Api {
+  trie: Arc<SharedTrie>,
+  config: SearchConfig,
+}
+
+// the value of `%XX` escapes and `+` in a query string
+fn decode(s: &str) -> String {
+  let mut bytes = Vec::new();
+  let mut rest = s.bytes();
+  while let Some(b) = rest.next() {
+    match b {
+      b'+' => bytes.push(b' '),
+      b'%' => {
+        let hex: Vec<u8> = rest.by_ref().take(2).collect();
+        match std::str::from_utf8(&hex).ok().and_then(|h| u8::from_str_radix(h, 16).ok()) {
+          Some(b) if hex.len() == 2 => bytes.push(b),
+          _ => bytes.extend([b'%'].iter().chain(&hex)),
+        }
+      }
+      b => bytes.push(b),
+    }
+  }
+  String::from_utf8_lossy(&bytes).into_owned()
+}
+
+// the decoded value of `name` in the query string of `url`
+fn param(url: &str, name: &str) -> Option<String> {
+  let (_, query) = url.split_once('?')?;
+  query.split('&').find_map(|pair| {
+    let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+    (decode(key) == name).then(|| decode(value))
+  })
+}
+
+fn lines(body: &str) -> Result<Vec<String>, (u16, String)> {
+  let body: Value = serde_json::from_str(body).map_err(|e| (400, format!("invalid JSON: {}", e)))?;
+  body.get("lines")
+    .and_then(Value::as_array)
+    .and_then(|lines| lines.iter().map(|line| line.as_str().map(str::to_owned)).collect())
+    .ok_or_else(|| (400, "lines must be an array of strings".to_owned()))
+}
+
+impl Api {
+  pub fn new(trie: Arc<SharedTrie>) -> Self {
+    Self { trie, config: SearchConfig::default() }
+  }
+
+  // the config of every search
+  pub fn with_config(mut self, config: SearchConfig) -> Self {
+    self.config = config;
+    self
+  }
+
+  // answers one request with its status and body
+  pub fn handle(&self, method: &str, url: &str, body: &str) -> (u16, Value) {
+    let path = url.split('?').next().unwrap_or(url);
+    let result = match (method, path) {
+      ("GET", "/search") => self.search(url),
+      ("POST", "/insert") => self.insert(body),
+      ("POST", "/remove") => self.remove(body),
+      ("GET", "/stats") => Ok(self.stats()),
+      (_, "/search" | "/insert" | "/remove" | "/stats") => Err((405, format!("{} not allowed on {}", method, path))),
+      _ => Err((404, format!("not found: {}", path))),
+    };
+
+    match result {
+      Ok(response) => (200, response),
+      Err((status, error)) => (status, json!({ "error": error })),
+    }
+  }
+
+  fn search(&self, url: &str) -> Result<Value, (u16, String)> {
+    let query = param(url, "q").ok_or((400, "missing parameter: q".to_owned()))?;
+    let limit = match param(url, "limit") {
+      Some(limit) => limit.parse().map_err(|_| (400, format!("invalid value for limit: {}", limit)))?,
+      None => LIMIT,
+    };
+
+    let mut engine = self.trie.engine().with_config(self.config.clone());
+    let failed = |e: crate::Error| (500, e.to_string());
+    for c in query.chars() {
+      engine.try_query(c).map_err(failed)?;
+    }
+    let ranked = engine.try_ranked().map_err(failed)?;
+    let options: Vec<Value> = ranked.iter().take(limit).map(|m| option_json(m, &self.config)).collect();
+    Ok(json!({ "count": ranked.len(), "options": options }))
+  }
+
+  fn insert(&self, body: &str) -> Result<Value, (u16, String)> {
+    let ids: Vec<u64> = lines(body)?.iter().map(|line| self.trie.insert(line).into()).collect();
+    Ok(json!({ "ids": ids }))
+  }
+
+  fn remove(&self, body: &str) -> Result<Value, (u16, String)> {
+    let removed = lines(body)?.iter().filter(|line| self.trie.remove(line).is_some()).count();
+    Ok(json!({ "removed": removed }))
+  }
+
+  fn stats(&self) -> Value {
+    let trie = self.trie.read();
+    json!({ "lines": trie.len(), "version": trie.version() })
+  }
+}
+
+// an `Api` listening on a TCP address, the requests are answered on a shared pool of threads
+pub struct HttpServer {
+  api: Arc<Api>,
+  server: Server,
+}
+
+impl HttpServer {
+  // port 0 picks a free port, see `local_addr`
+  pub fn bind(api: Api, addr: impl ToSocketAddrs) -> io::Result<Self> {
+    let server = Server::http(addr).map_err(io::Error::other)?;
+    Ok(Self { api: Arc::new(api), server })
+  }
+
+  pub fn local_addr(&self) -> Option<SocketAddr> {
+    self.server.server_addr().to_ip()
+  }
+
+  // answers requests until the process ends, `threads` at a time
+  pub fn run(self, threads: usize) {
+    let pool = ThreadPool::new(threads.max(1));
+    for mut request in self.server.incoming_requests() {
+      let api = self.api.clone();
+      pool.execute(move || {
+        let mut body = String::new();
+        let (status, response) = match request.as_reader().read_to_string(&mut body) {
+          Ok(_) => api.handle(request.method().as_str(), request.url(), &body),
+          Err(e) => (400, json!({ "error": format!("invalid body: {}", e) })),
+        };
+
+        let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+        let response = Response::from_string(response.to_string()).with_status_code(status).with_header(content_type);
+        // the client hanging up before the response is its problem
+        let _ = request.respond(response);
+      });
+    }
+  }
+}
@@ -0,0 +1,22 @@
+// fuzzy search of a set of lines, stored in a trie and searched one keystroke at a time by an `Engine`
+// (single threaded), a `TEngine` (a thread per branch) or a `TPEngine` (a thread pool).
+//
+// let trie: SearchTrie = ["src/main.rs", "src/lib.rs"].into_iter().collect();
+// let mut engine = trie.engine().with_config(SearchConfig::default().with_path(PathMode::default()));
+// "lib".chars().for_each(|c| engine.query(c));
+// engine.options() // ["s/-l--i--b-.rs"]
+
+pub mod corpus;
+#[cfg(feature = "server")]
+pub mod daemon;
+mod error;
+#[cfg(feature = "server")]
+pub mod http;
+mod search_trie;
+
+pub use error::{Error, Result};
+pub use search_trie::{
+  score_positions, Alignment, Case, Engine, Grapheme, Highlight, LineId, Lines, LiveEngine, Match, NodeRef, Order, OwnedEngine, OwnedTEngine,
+  OwnedTPEngine, PathMode, PathNode, Rebase, SearchConfig, SearchEngine, SearchTrie, SharedTrie, Snapshot, Symbol, TEngine,
+  TPEngine, Token, TrieNode, TrieStats
+};
@@ -1,16 +0,0 @@
-pub const LINES: [&str; 1007]  = ["HOLGCFBJKEJBIHJJMNFHFEOHGKDFBDOMIHD", "HODGOJMDIJBKFAMFECHGEIOEMGGKBIGEHNGKOBMLDBOANJKEAOGOLGNOHMKDBCNDCECEFHMBAGDBHJJFDLOLKKDCALCMHJBE", "HOAMJBAKOKFICAFALKJDNGOBHBBODLGGNJCOJKCOCJBJAEDHCFCKEDLEINIEOJACFNJD", "HOAGGKKNMCEHHGEHFF", "HOGKFLGIHDAMKGFKAEDMMBGOKNHMIDFNNJCNDJNLJEBHNJBNELOBEOKILJHIKBLEFINANLKKKNHNH", "HOGHCEEHCJBGBNDFIJDOEHJGNNAIHEOIIO", "HJJIEJIOIOKILIAJBNFLNIOFFOMADOCJHKEBDAHELEFCOCFENBICGCAD", "HJAHDGHDHEKGJKJBHEEH", "HJENLBCENGBCBKBMDMNAHOLINNEEGLONDI", "HJCFGEMIELANNOEKDBKIABFLFHCNKGBCAMNDCKBDCIA", "HJGBCEEINKIKFJDDIAKBAHHHMBNNOJGDKOJHNBAGHBHNMFNJIOKDFOJNFDAHHJMIJBJKACGGNFHGBBKEI", "HLMEAMKBLJFHGNIAKKBC", "HLIOGCOMGGMCNMHAGBBDCFBFGCDCBBCCHOHAGBOMICHMODEMEIJLONJDCHOAMMIKD", "HLDNAEIDOGHOBBNNGAIAFCDIMMCFJEKLAMOGEHGKGKEGNGNHCJIDMJKMCJNA", "HLDNIGMIICJDDOAGKEFNCBHGDOEHLKAKJ", "HLFDAFEFKHDAEACHH", "HLGCCCGMBD", "HLGILOHIEOBBIDOJNGDMGKDOBKINCHLAEFAODACJOMNNHMDGL", "HLJMFNLBOOCDDHMMHGONCCGGIOIBMODAJCJAFBNELOFGNELFCLLKHCGBALOJLGANHEOBOKNIJLIBAHNMEBBLEGGHMGM", "HGGNBGBLDLBABDHCFEMGECAKKINABICENKAFGLDOEGBEOBNEHBJOGFFKKFALDNKILMMGJD", "HGLIDNOKOLCABHEHAHDJJHOKCFAMMLNEADNDOONMLMKNJHNEJAFO", "HGNMHODGIFLDCBJIOMFKGFOHGHKHMIIHNDEJMIDKFJFMOKHLJCAGBFOHGBMOFOKIBBBOHCFNHIDDABBIA", "HEJBHMNIAIIJJDJMBBGFNLBDAEMOOMFLDG", "HECCIHNJLNOMGEHGJFDEDHLACMMKFAHGDBHAB", "HEOBLKCCKJCMEBKKMDNCJLNGFEAKGILFBIHHKGFENKAHHICEEDENN", "HEGKGMCLCDCJHBCHMFAGHLCAEBDFCABIHIMJNOALIADAAC", "HEDJANBCFHIGLCONAKE", "HEDENHHHCHLHGMKOHCAOKIOBLLNEMLBOHHEJIHEFJLAGLI", "HINLJNLDOLFFEHADLINALGNGLMIIJGIEJGFEBABMOCFMONDECOL", "HIEHHDDAEIOCKEIOOAKIAMGOKMDGGANBONGCMKOGGHFJGMIGFHGFFHCEM", "HIGJJJGDKHNOKGKFMICDLEHGKGIGEGOHAKDMCIFBLNEBKLIBFEBHCIIBAAHOJCNGCHKDLDFGMFHJEINNENEBELCABBM", "HIAAEKCKGMNLFBMKIFNELJNFGDFJFHIAOJMMIFKMOFAMFBMFHJBBBDDCJNIKMADNHKMKOBBKJF", "HILCOFKHIIMJBIMOIJAFEKKCBNABJFDEHJOGENEKIAKBBINCKMLGLGDCFAKFOLHKFFLLMICIMGMJJL", "HILABDALEBHONKMBLGBDFHOHFEDDEDADJDDLANDDCJHBIKHHBOEGCKGCEEKDHCDGIAHONOJNIOLGIBGEEMJJ", "HILOHCDLAAMMFMDLKKNEAAAKKJBKEAIDNOGHEOMIDIIKADODNJFCCJKADK", "HIOKICLILIMALLDGOEGDLHGEFALNFAILICHIMJHGGBADDOELGELAONFFFLDIA", "HHHFCDFIMKOIEHBKDHAOMBEOEELIAALHAD", "HHCOJMOOICJBOFNAEOOOMMEHKBFKJMOAKGJAKHFGEEHLEOAMLIMBFHBNNEBDGECEOLDGALOEBBNK", "HHKGBJODMIIJOKMMMANHEIMGFAOIAMADJJEKLGFNELKOGKNFMJNFJODEBGBGNOKGF", "HHLAMIBKHNHANJNOKFLJAKLFAGOKJEKNBDCMABNGKCEICEJEOOMLCCLHKBBKLDHEFDKHKGLBLNLLBMMDMEO", "HHLHBMIKEIMDKBKJCACIAJGMKGKAIEJOKJCDF", "HFAACMLLOGNMAKGOHBHODBOLKJBHBMMBOKICJMKNLABKAEJFOGIAGGBDCDIBEBHM", "HFHEFOHFKDDGGGGHOEGI", "HFHKELBMBDBIKGAMJEBGAIELBOEECEAIEEBMNMGINIDANCMH", "HFMHBFLJBLBFNAMBGGEFEIFEHCGEGIOFCNHEABDKODNBCAJIFFHKHKNMHAAJLNDFNMLHBFONCLMK", "HMDKOKMDDCNBKEDANCMBAIFNKJOAMMILEGLKOCAJHELONALCMNKGOADHOFLJCDGKNBLHHOIBIEOGJNFJBHEEOC", "HMJJLGMLFKMDFBDHMMBAIOMIDMOLGNIAFDNCCMANIOIECKBHCFHLIJDACJHNOFCMGBHEOOLFOEGCKCJJBKAOKFKKAJKMFKHHBEA", "HMJOGAMNKBLGGLHKIFNCLLCJEEFMLEODFMJCECIMHCCFAEJJANMNJJHIJOKECCDDLILEJCCJHKB", "HMMHDKDOBIOGELNJCEGJMNJEGGNIGHNDBIBLBJDABIEHIJHGHBMONDAGCHBOFFNJJNMKCCKMMDMDACFHMDMHNBJB", "HMAMKGNGEANFLKHHHMEJGGEEOIIBFGGFOHJCAD", "HMGIFFOGIIDHJMJKEILCDLEELELCNOOJDJH", "HAFMKJMKNJGOBIAHHCCDJKFNBOMMGIIOMIHFLNCDLO", "HAGFBGFECEDKDGFLLBNIMGLMGMEECMNFEJKJKILNJFHALEGHGLONAMKOBHLNGIKKKMFE", "HAHDCOBDADIBMFAFMEBHACIEFEMFCKJMCGGFKBILCGJECAEAIOMLFAJEACIKHNFBLOEIGNFCDHHDLJOMJJEBLDAMMO", "HKHOOBMCFHHHOBFCHHAMCHKOJMIAGMMCMKJHMEOGDLKKKOIFGMI", "HKABMECNBEJGO", "HKCKJFIFECKFCKFNKLOLADILFGIIIKOIGBL", "HKFKKEBFGJICIKIBGJJNCJOFDFHHONLAGCDBFADCNMFJLJABLGCENCCKEEDGLGKJCJBLCGEJILOFHMFBALHEFDEDKKJMDFMM", "HKFDELEJLFAJODHOEJFGJIKNLMEJFLGGALKGJKELJOJKKKCBDOOLJCDKFEBOJNJNLMKDGKCKKOBKOG", "HNFHMHELHGNEKMFEMAFIAEDNGAHAIHBNMGAIBFBCFODEEMOIOHODICBALGBGLJOKOFECLMJOKAFDIIMAFHHECA", "HNOJBAHEJN", "HNOKHBLKLBJONGKJGNAOOGFHNOLNFOIMILOCLIFJJNMFB", "HNAKAKFCFHOMLIGNDBDJDIOODAIBGDKBOBLFKEHGAIGLGNFLNNDDHNMIHJN", "HNAHKBJNGAAOGIFECNDKIKOJAOIIMJNCAIKJICIDDINAIMGLEIBJHKJFNAMOOMCKCGHAJNGANLJLNDGHAI", "HNMHMCOGLBJHIFBJLKOHGGGJINGOMGFMGD", "HCDHONOGLCOKCAHLOGNIACEHOJNJOLCBOFDFDJFEBEFLLNNJCNJKAKIDAEDKNDDFNOOFLGJOH", "HCANGILDLJOODNNBJBKBACNKOLKF", "HDIOMEHANAKNGCOMBDLMCOAJKMBOHOMDHJNHJLLJ", "HDIAEHLHIFALIEIKJIODLEKEIOBMNDCKGCDEILFJOKFOJNHHOLMGHJ", "HDGDCBCBIGKJFGEDHEEMGBHHAGBEIHFN", "HDMJFNDLONIBKFGGOCFELIHAJLCDE", "HBKNMCAANEDNLKGOHBNEMGFLOHCABHMLJAHHEHCFAFGMAFNMKALFNGKEBDCDAOOMMFCIFJBLLBDGBIHHNAFGE", "HBKHFGDFIHNKGHJNIFDGKNEMMM", "HBNMEOMAAIEMEENIC", "HBBLCOFAHLBHOLHEEKGLMMNCJFHIEGCFHGBDBNJOAFLHOCGLEDIMBBNIMIOEGFOJALJIOBANJMGMNFBLOBAALNNCHBEGHBHOKN", "not a line", "KFIOFGJIDCCOOFOIIEIJNLOCIDALGDICFCFODGILGOLFAHKMBLKG", "KFLBCFCDNCODDMOLCDOHGKAHHKEI", "KFALJOGDIKEEKODNHKIAKKOKHGNJOCABKIBBKJOMGCGKIBACODHIGKAJDKIFAFCBDDBEMNIL", "KFGKJLHNKOLKCHDBNIIDDMDOLELEBJHIFAFHLKKMLMOOLDFBJNBKOJ", "KFGHMNKEGEHHOI", "KFJHOGNEMJMMLFJHHIKAAFNJACNLFKMFHCNBDFHBGKHNIJH", "KFHLJABJCNKGODNIJOBFOFHECLMMDCLKNKJAAOBFBFFJF", "KJABIGGBHLHAGCINGJGJEDJABMLJIKNKHBFLGMFNCCBODLCKCHA", "KJFMAALHOOEMLNIMCBKCLFCIEGLANJLOANNCMNHEEEH", "KJDLCHOODFENFODBFBDNDNDMLNGCODMGNANHABECEEOEBLFEH", "KJHDIELNGNBMBBBMOADNIBENKNMEBFDCEGACOMFHKJIMFJMIDLEMIIELBGCI", "KAHDJGAOCCAAOHBMMMAGABMOHBDFMONFDFJMFDGFEBEMOAEOIEGDODKIHNMBIGGGCFFFJMBAL", "KAHGHAIECCEBEIHKHLDNFAHMLOBCKEICCACMNEACIMIAGEAEOEIMIFLBLADAOBMIIGMCANGOMBOKHLMJOFNO", "KAFILOLMGAMDNACGMNGBHJHDNFJCECOIEOECEOCLMIIEOFEFKHINADDFDFHFNILGIEGOCHLMDCEO", "KAGEFEAMKBKGHCKHCAGJMKMFCJELOMEKFNBMLLCJFILMDBGFALBIGMOAFJECHNAEN", "KAANNFILEDKGODHLBIFFBDOBLCNNCMHLJDCLCBMMOECENEFEGLCFJEEJGOCOFALIAIDNGLMIBOJMGCKGEFNKFLJJGGFDCOHDK", "KAKKAGNEOHLENLBFBFCLKFFIEGHFDHJIOGHCBBJIIMLJOKIJNKNMJAA", "KAKNDGOHFGLGENCJLCEMFELCM", "KAOJLIAMMNNCNKKJGHEDIGBAIJNEECCIMKFMOMHBMJNHBLHOGHLIHLEFIABIOBKADAEKGCIK", "KKIGMINMAFMHOLOICHBEENKLIIEKOABAIOOEHIMGEOBALKIOKLFFBFOKFHNALCEFEDHKONOGMF", "KKLMCMEMJGCBDLDGHHCEG", "KKLIMGHIGAFNHKKMJADFKHENOOFCEOHNHAHDKFHONKJBICBDGCNECOALIEDDKIBGMJKDLDNGHM", "KKBOMANMENHOCNKLLIGBCOKNI", "KNBGEONOFHMNIGKDKGIJGEFHFDEHKODGOLMOEHD", "KNBBAHMIBCIMEGFAGKIMFGJOIMEAHBGEAMODOGEINNK", "KNIAIFJNINBFALNMLCCHKBBHLNNLAFNEDAJCAGHKONAAKLKKLNGCHFMBEMGJDHFIMCFLIMCIFEHFMDAEJ", "KEOINFGHELNIHFLAHFNLMDBGEHIKJEFDHFIHCGINCLGICJLNLNFCCGKHGICCMNMKIDHJLBJIFMBLFBBKAFEBF", "KEHIOIHEHILBDCDEDGLMFLAOEMGLKAMFCIGIOOAKECONKGNAD", "KELGMCMLKDGMJFAKENEIFIJNBJEANMDAMHOJEBCGKEAABMDKLKMOGCOHNNKFAGC", "KELBDAABAFAIBKIIHGKMCEHAJFDIKFGEENEAJCG", "KECIIFAHDBNGHJH", "KCAMJEBHGKKGNKOADNGFEGBHKALIN", "KCLKNBOFBAJAEHFIGKCFOBBIGMFJJLAJOHKIHLD", "KOCGFEBHACILCCJBCHIMMINHJCEMNFFJHBKKJAGEGIFCMMFIMMGD", "KOOFFCDIIDHHLEMFOFNMINOELMBMNMLBBFDDHHBMIFGAOAOGGBBFNEKKHLCMGBAHKFLFJIDAKEMLCLH", "KODJLLBNGBKONFJNEEGLMIHFMECAHANDIKBHKLBHFHHFNJOFFICLFJCKA", "KOFGOGFHCEJAKAADOMGMEKNAMEBCLOCAAAHGBCJOIHNOGOEDNLAOCKMKAFDLHEHHKD", "KDMELKKAELDNMJMHMHCDDIOEHIELHMJCMKMOFEKCKFMNBANOMCIJBFNGFIEAADENNIKFGDKGBALFHCBBIIMAKHHCDMHH", "KDABFLCBABGKJBKNFJKMOHFKKKCGOOFDIBBIODCNBLGJFLEGBCB", "KDJNIAHEMEGKCNCKGJAHNLCBCGNNMMELMFIHKBIFINGDAKIAAEKBNIBAMBFABADILMHANIDBJEDONDKFOLIEFB", "KHIGJJEJEEKFLMKIBNFFKJIKLDDJFJKKNCJFNDHKHBMOLNFHBCHJECHFFABHNCECBJIDFMDMCOABLNODIGG", "KHAOCNDBCJIGBDLBMIBEOEAOOLBOCGMOKIAMCAENNOADNAGMBHGMJNJDCBLGKOJJ", "KHEBCLCNBJADADJLLIELDEIBFNCCCDOLMNO", "KBCLJMHLOCDJFIGOMJBKFJEHD", "KBIGLDKCFGDKHFMKNMBBAOHIBOOLICCIHHMBMAJNM", "KBKMMMCBNFIKDHOGGMAIEECOADBAMAGEDCDDBIHLNBANHLABFCILKEIMMADKKABKLDEEKFIFBMI", "KLJKANKKOFANCHBJBCMHICN", "KLKCBDDANAOEOICOGEFLGDJIKMICJIJJDHMNHLOACNDCFCGBO", "KLKEHKFLJN", "KLIIMFBIKKGLMFNHOKGOKHHBFNNDDCAHICKODILKKEIEIEINCMNKHJAJ", "KLMOELFAJCKLENMCHFIEKBMCKHEOINIIEHGCCBIHG", "KLOKLAMNJJJJJHLJCCKOOJMCOBNELLANJJHF", "KLDDMCJKAOCGGDDKIONGNLCJFBOGBEMHIEMBNHDBLNMNENKMOKLFIOBE", "KLAOOIAAHEFJBKMKBNHDAHCHCJNHHCAKDDJKNAOABAFKKCIAOOHGECFEGCGEAADGNJMNCDBJHIJBMHCDMGJIMDBAMHMCO", "KMMNGFMFMKCMBBHABIOMMLLNGIHAJMCFLGJBCJDJCMEAHCKBDJLDIJEFMDBBNBOLDECMKNKDNGOEAOFENDHIIFLJMIEM", "KMKHEEBDODAOAFJFGHBANDAEACNFJEBDIMCFAGANKFHDABDGAHBAMLHJNOIGBAJHBLJJMBBNMNMKHINFMBKAA", "KMHCFFLDNCKCEFCNKCGIHBODLLKFKDJGDHOELBMBFEGKBIONKFFKNLIN", "KMLNGHCOELHMEJIBHOM", "KMLABKNFNHOGAKCHLNFBAJOLBABBMIGLFDLOLONNLEDHFOIBEHCFCHIM", "KGKIONGKGCHFNLLDIDH", "KGEIHJJBHDFLHNAN", "KGGBBEHBFCMHFENEMFCIBCCBGLBLIBDIBEMMIO", "KIDMIJFGONOGHMNEFKILDIGFJIGKHABMNEEIEFFNCDNGDKDDDGDMGAGMCAIDKOKMFDGJLCAEHBBMKBI", "KIKJMOAACEMCLCHHHCFIEBAGMKBHMFGHLCNDKFNOKAKFCJJIFLNOOFDIBBKDKMMBN", "KILCNFNLMLFIEHBCEKAIOOEOHLLOFABABIC", "OJMHNBANHNIBAJIOELDNEJDGHDNOAOBJHJCEFGAMLAMJJBMNICHNLCOGABDJFEAHJLKCHLKL", "OJMLCAJKMDKMGHDLADGAHDDFCKJENDNJEDIEMDEEMBBKONNHODJGMMNKEIJLGKIOLAOIKKJGFDHLNFGLKIOIJFC", "OJJJFBBLMGNCMNIJCGLHBNKMOAFGMBNEFAGNHOOF", "OJJFABKKEBFMFJEALEAKOJINDKOMHIIEOJFODDKKNFBMEOACONAAFCBIGMGEHLAOJAODNLBDODJBOFNFNNLBL", "OJBBIOIDNAKGKMFMKMNJJHLDFBBKIHEEHCJCKLNEIAKFJLONHNLNDCAJIJFEKNDFDFCLIFKFEHKFBDALKAEABBBJDGNGND", "OJCEKEJBAOCMGDAFGABDGGJJFDHLEJANNIKIKHMDNAEENOOHCAIKH", "OJAKKILNEIALJHLDIJOIBNONILEMLDACLLDALCJGCNCLDHBLCBMJFDLGMLA", "OJGMIALJBLEEJFNBKMNEMNCOGOAMLMJGGEE", "OJNAOFBIAOANONIOBKMDMKKLKIAFBCILLIJNOODLDMBACNFNFKCLJBKBKDLIBGHOEMKLLMKOMK", "OABFCCDGHKNGJCNCCNBIHBHAENIKGHGLNMLCFNEICAKKKNFMKFIKOMLFADJMCFOEDFMDGIEMANCMFKKHMKA", "OABIBGIFNDBJLCBHLNANFJOEMO", "OAJNNDACEBLHJMFEFOICKJBCKJNGLMKHACLJLHNGIEKCKNLENNMFGEFOHECCMMGH", "OADOFODGNLKGOEMCCKBLBEOOOEAMFABHIBEOKBKCCAOIAILMKGCHDKCLLHENOONHANLNIFKIENFBEAKEOMHADDMDOEHKAMG", "OAKGDMLKFOINDIBGCDFMHJAE", "OAIDJDABBHCMCFGCFHMLMDIMDACGMACFDBAEG", "OAIFONIEOKADJELCMAGNHBECBDAMIEEODFIAEEKDDEHCBDHFBNHEDAIMKFOFGFBJ", "OAOGJMNABLNEEGBLINIODDOJKHLFAIMIIHMMFMDMCOLNBNEMJLKINBCEL", "OANNIFEGALFFHLADHBLGLGABJOCHAHO", "OAMIKHGDABFCNDDCNHGDHLIEGGDECGCEHJOHDKHHDLGOHAIAHFKKAMKNKGKOBIFMFDMKFAKEHJHDFCBF", "OGCIHMEJLOHBOLCMKENGNCGFCGMEEENCJKB", "OGIINLNFKAGHBLAHKNLDHMOAFJEDOLJDDMFANMDFOGH", "OGMIODJFDHKG", "OGNLOHADEBHELHMIOBICEEDLDLCDGHMADKACFGMLNGJHFLGMCHIINIDHAJDDJGIJGJLBFLIKEMCMC", "OGAONGILBCCJDFCDLHKOGBBGBFHMEAAELJANAMEHAMJJCGMK", "OGAALKGDAMENNOLCFGOECMJNMGKMECGHDIAEDFOELDNCNBDFBACFGGODJ", "OGDKGNJNCNADLGDIAONNMBKHECJAJKACDHAAOBMOB", "OFINBMODBMKKFJBGJDGLOFDKJFDOOCLKGFGHHJLLLFLJECKFAMMBNGOOMFIIGNJONCJLDBNONCEABDJFEHNIJN", "OFHDCECCGIMCLLGH", "ONMGDNMKEFHDNMCJIEALJEHDJMOOAEIOENBHCMFDLJJGFECEAOEGBMMBFBIBGGB", "ONMJKOFICHCIAIKJICECJNCAACMJKAKGNGJENELGDJBDHAIGJBIKNCFOIHIMCKMHJOCFMAFJH", "ONMMJHKKIGJJKMIHCKLHKEMIJNJFBMCCNLEJOEHAODGGLHMFFGKHINJBENILOODIJGABGKLDMLCCLD", "ONAGCLIFIBMIDNCCHFEICDLBNEJLCBBDHJNBBAEGHNNAEKLMMDCKOKIMLJKALKNCKFCCDLBMHBJBLFE", "ONNMGAANKLJ", "ONNAEKFEHKMIACJEDBHFEMFACGDJICFNMGELINLCJHGIGJ", "ONEJHDHHEEMBGFCHJCDDFEEOAOJIEBMBNGCKNONLBNGNCONLLCNGCCLALKNNAJOJAJGLCFCCIEEAMGIAAADCIAN", "OCJMACBFLDLDKDOOHGCHCAKINNNHFLBHFBOJIFLGMGHIFALCIMJJF", "OCGECNAMACHIKHMKJIHEDCDDBINCLDCLBKJDHMCBOD", "OCGFKHOCIBANLDGCJCFMKGFFADLCBNMBNIECACLIHN", "OBLKDNKONBAFHKMEGLDJGHFFGDAIIOFFIMIJOKIENDJI", "OBJDLKMMJFINGCDLADJCNBBAJLILDNIDADLNAHKNGNENGAOEKGMFLFCFMDHJJIBF", "OBMMINJDIFILEACBFEBBMANLCBCMNHMBJLIKGFEEOELKAMFOHAKGKBJEDMEDIKLIIBFOONNJHKEEIDMJHNKM", "ODJGBGMGKCJOAKDBGEILIOEOFGOKGKDNGHGMECIDNMLJOFEBMBHOGKCMEJOGB", "ODKGJMGKCLBIFGJIGNOFDEEHHICIELDFCKHMLCDDHOKEHDKIEBBMFEFIMEFBDKHCECAIGIKGNDLMBHHAFIICNJ", "ODKBCAMBEBLGLKHFLEBMIBGCKEOJLNBNBGLMAAHGDILGIOOJKAGNMKLONFFKIICBBHFCJBDFJFFOBONOMDELLJEMALJCNFIMH", "ODNIFMEDMAGGOKNDCABLAEDJLLMDNGGKLGKGOONHFNEO", "ODCGKCEODCCAMHNLAKCENKLKFHFKJHDIOIEFGJEOFKNAKCJBDOOJBKHINCFBFHBMLFIOMJJEAJNJAABOKLKCIIHLLLGBJKHEB", "ODACIFEFBMHGOKNAJLCEHOOBFAKLBEHIAIFDHKGLIBNCJGEOEJKFBMCLFBGDDDKJALMHMGNNEEHDJICBCOLHEHFK", "ODAONCAJGICKJDIAHAKEIMKKEBCKGFGFMFIFDKDMM", "OMGOHBMEBCENJLBAOEIOFNFFBGOAJLFEOONGLEKLKMEMGFJHNBAIECLKKJHGCOEFGHHKLLJFNHOJGDIJNMHOOEHOJMGAHBG", "OMINOLKOLDBLFHJGGK", "OMODFKAAFAADAIDBKNDAJMLJBIILGMMJHDNBNHAOHLIKOGFOMHEJJJAEEOHEIIGGMMBBGKNK", "OMLLJAELMCGNMMLFMAOOBJCOFKBNHFL", "OMDIMEFKIFAGBNKNHNFFOBNGGKGKCDOFCNMFKLBNAOFILONLNCGB", "OMEFMCGNBGCCIHLHLFNCFGKGDAFDNIAINKLGLOACIJLLDEBFFALEDJ", "OKKENOMCHGHGMGJJBBGNAFKHMFNOKIJHHKADBOHCMCEDGCOOBLILJONLLKCKLMOGOL", "OKCJBOGHBHGNLCMLK", "OKAIFLAGCDLGFFBBCB", "OKFLCFDOIMOLHOGIOIJO", "OKHAFDMIDBBDJKFNGNGNNGHAGIFOBKGFANCDHDIEENAMHKHGODLCONLEMLDANLFLKKOKNO", "OLJOMLAJOJBEBLOKKHKLBDDMIEFDOFGJAHEGNMLMDLJODIHOMANLCINBJMINNLEFBEHFHEOMODEEBDCDBNILLJLEKGBBAOIOBGG", "OEHNHACKBEBAMEMOFLKINODKOABFCAEIHNKFAI", "OELOOJOMKMLDLCLKDLOBDMNHHAHADGFIEFCDDLGCA", "OHCICKHEEHMKMKFMCAILIJEIDLLIAMAOIHGHKIODBAAEHNJCCJNJCNDCFMOAMFLMNC", "OHHDLIENHNIKBIKLJLLFABAAOMNLFLKCKBFOMJJGDBLGHFEJAKNBFEELKIGNMECJIHMLDIOIIADDCNDOFKDOFKBIHFB", "OHHDOOBJDGOOHJNBAHIGJDGNBMFKIHACAKEDEAKAMGMCDLJLDI", "OHIEEKELBJGBHNGGCDAJOJMCEGMOIIIEBDFCCHCNLAADKMJGKLCGNMLINLGAJGNEDHDGLDIFLKKH", "OHINAJHKFAL", "OHIIBEKCBFDLEEIMIFKCJBIKDALNBFBHFAIKOMFAEHLI", "OHEMOLAEIJGDEGIBEHDLANFBBEEIJDIECFBFHJMMMEODGJKMDDI", "OOJLLJMHKKCDJGLMGDNCGCONNBLEOCCGFKIAOJGEBMKGOAEKOCHGICFKFMNJMJNLGHMJOBHCABOLMNB", "OOAOKOLKKIOMDFKHBNOMBEKCILMDLNNFFKFENFOGJFLCJNOGOBAHNABOFHNAIKL", "OOBLAGCCJDCHMNNDDJNLOEENBOLNMKJHGCOEFBNMEGCHNLJOCBFMEHLNJBJMJDDNNIABNCLGCHGFDCCIBDNIBCJAKN", "OOBDJGBDIJLEGCNLFLLDGJDBDJBOFHDDDOFDIMBDOBCFLGN", "OOCGEKHBNBHCLLNOHFOOEHDFFDMKFDEEGCEJOLFFBLHKLFJN", "OOGNBAGOJNDBOFBONEJGMIBLDDJLCGGAGAOMELBEGDIIEIBJII", "OOFGEGCKGGOBGKDOEKOMMMAFJJDNILBAAJNOHGIONLHNIJMBO", "OIAHOGHJIEEKLNBGEKMALEALHLCBNGAIAFHIFOGADDODHFDJKOOFNNB", "LCIAKGGHBLILFBODMHHDMMFFACJKHEABGL", "LCAGGGFCHEGNBELGNLAJGBONMCDMCKJLJIHCINKDKHBNMEIFFOAC", "LCAJOAFCHDBNECIMCECOJOIHMJNHNDJMDGJKAONKHCDNHFDFKAOKGJNBGLNMDJHLOCDLNFGDL", "LCDMOKIBCDL", "LHICILEIMILMLEGHDMLKIFBBCCFCCFMCEHMODAAMBKCO", "LHAMNKJMMKBHAFAMCOCHCBLNGMGAJBNNDLHLIANAHECEMIBNCFBLEHMILCGNJKIDL", "LHLOOOJBKAMLAJHGALHCMJBLLFGEOMNFJOLIDNDOIDIJDAEBLLEHHLINKNC", "LHEEIMCHDGMCDGOAMDHJHIGEKCDGFCL", "LHEBNIDGCIMFACCAAGOIBHCFK", "LIKIGBDJGHKFKBFAJIDLOJIMMBLHKLGMJLBBFNBEHCNJDHGCJHJDALALKOC", "LIFDLDCHDHDEKOEIAOCHIJKDHE", "LIMOIBCIHMKDDJGFOAKHJBLNKNNKJLFGHGHMJJEJDHOBFFEHOKBAFFGLLDOLHOHMIECDEIHEJMHLGCFCNHMEBNCBFA", "LIEKMHNOACEKOOBJBJNECMHFHGEJJFODJDLMLNADGAMAKCOLNFKACCFFO", "LINHFGGGOMIEBHCIIMDOFIKANILOEKMAIEDLHFAJFMKHHFNEGGEEAHFLDKDNLFJNCOMMOAKHLOACGB", "LIICMCLFJCGAJBGGNJJAJNBFFCAOKAGLJDLFHOCBAOLNFJEICNGKKJMACNIFNLCJADNIJKNAONAMHAFCCLIICOKFLMAJHIGNI", "LKOLODLCGLCFKEFJOGNCABMEKDMOFIFBDMMFABJABIILJOKCAAAKOCFIDEIGHAAEBEHOKJAIHABHDHIGABIHKO", "LKAJBHJCIJIMKADGGGECOJIEGFMNMHBMIGGBEFGEGOOCKMAOBDAGLFGCMNGFF", "LLDGOFLJLIAHFHCFJIKMILJCBBLFNIHILAMINFIEBENENFHHCFOGJHONLHILJGHEFCCLJIOBHAEFMMNCGOCIGD", "LLHFNMIMCMLMOHAFBGFNJNNLBKNIDKHNHABLOGAKFNLCDBNIGNLDOBODOGAOMNEKMFCHBHOLLBNCEOKCLBIEKNOGOALJJOM", "LLNMMKEENJBCGDCCGFFFFFCLEDAGBCKFIDFAJGKFHOHAENLDGBFNJCAKJOLCABGF", "LLOCGACCNJJ", "LEFHOOGDHFNBHODIMGFLJDCIIMLDNCEJINLHKEIEHHMKOGODELMCBMOMHGJACELLHFKDNCCIEKJDLBIJCALEL", "LEAFAJOAJCNABKGOBGIJLOIHMGNHLAGCLIMLNHICJDIHEFIEGKMCKFHKFDGBHBGEGJIOCLGLIGLJDEFJKFMAKJEANLGBFF", "LELHNMLBDCFABHFAIFOKKDOCDHCCEAFAKGIKKIKAFHFEFH", "LNIHGLNEBFGKGIIJLIOKHJBIAFJIIDOKABKDLCCFGAFAFMAHECGHDGOINDOJFCMFBBJI", "LNONILKGOKDDKJFHEGABIHIAANBLI", "LNADGGEOMBINBFIIFJBFKEHBJJFIMBABGBHEMCMFNBKDMKLDLCEFOIJCGEBBFNFLFOOKJCAJ", "LNDNMJGGAIMDAOKLBKFAEKHFOOIEMNHCGMOKLBBKCNLCFIIHIOBJHMCFBDDCNOEAOLKKGBENGJLMHJDOM", "LNKHBILLBNDJKIILLNKLLGKDHOAFDGKDMMNHIHILODNN", "LNGJNBOCKCJGDAAMLHBDBIE", "LMAGECIACHLAKKAHIHAMGCJBNGGACJAOEMBCMHAHOGKFILCCCIEDBMJBDKELKOHJKBGEHELIIFICBON", "LMAIOFOBCGOJLKJFNKCAAMJAJBBCK", "LMLCKIGHFCDBOLNJIGDNIFIJNMHNIFLKKGMMOCJGIJENIMNGHAEEAGBIIHGMJGCEJ", "LMLJBCBBLKNBGDIIAGDEANNMKIFONGEMMEMEKFDCJFNMNFDGHBDAOFIIDCGHIGFKKOCLDINJOELAGMCMHCJFBCMCCCMDKEF", "LMJDNJBLOFNCBMADENAFDMCMNIDLLKNGOOCHNOLKOKADDNKNBKNIBCFK", "LMKJKKLOLHMBIHAMEKHMBNLGFOGACDF", "LMKIFCBMLMLANIAMHNGDCLLEENH", "LDJBHMLKMDINBOFCLLDFBDMJJJOGCKE", "LDOMHBIDFBEKANLGNBLMIMHNOKIBNBJBJLBOBNCMCNIENLDJANDGBBMLOHMHAEGDJDJECGEKMCGLOFAODJLHDNAFA", "LDHICNLNDOFGMKKKCJBJCGCBBDBKCFNICLELD", "LDENHEKMDNKJABKOCDIMEBLMFHLNJFLGM", "LJDDJKNNNMIAFMCDGL", "LJFHGHIECBGHEKJCKEBLFLBGAGNCCKGBHEKHBBFMKICAFN", "LJCFFKJIEJLGEIHNCOOOAKJNDDLGCBMNNCLDCDIGOBAFHIBDHAGMFLNBNLNKFINKDODENKIL", "LANNKHDMHLOMFBEBGNOABAJDLCB", "LAMOKDEDILAHJHIBGBBADDHKGHKMICLNNCEMIDNHMMANMFAEJGHIKCJFGENNNFAMDEAHMFFDNODAJFJEJOCNBJOJNEII", "LAHOGEKEOLGOGFGAMCCHKNCKCAOKGKLJEOLCCNMLECBKJLI", "LACNKCJBGANKFJKCFD", "LALCAJIJAGNKNCNBGKFEFGCKBEGAGHAFAJKKBFGEBNJABCIMKOAJIFLMCFJLMDFBBJMFMCMGGCHEBKNIKKGDKLMBKOHODNGELNN", "LAIIKCBOBJHAOKEHCABMHFIOAAALGDNALFNNGJLOICHCCLLHGCEBIFLKICHFEJGONJILILCJOCGEOOBHHCHDO", "LAEHOJDGOEOKJEFGHOIOHNHFM", "LBBIJOBJJKFHOJDOIBJEMCHCIHHCBLOKHKKLHCBNCLBF", "LBBMLNAHHLLLNFFIOANOLELOGBGJLNMFABDACBG", "LBJNOBBBLFFBDBKLJBJKDCNMEMKNKFBIFAMOKOCGCLACNAKCEBACAKDCBFEGBFJ", "LOCGADGGMAFJFNIILDMKMJJDLFJINE", "LFDDAFGHIEAGKNDDAIIBCBJGKBLOMEOMHHBFLEBIFIFCMAFCLOOMBNKAEJGHGDIAGADHOIJFDKKC", "LFDDKAIDJAOFBMLKHKBJGEHIHAFKELEHNBDCNKEJOAJHKLBEHOBNNLFKBBHBMFC", "LFCGCOCNABBODG", "LFJGHIBJEICAFK", "LFNOBGDLBELOJBDKJLOGLGIGKDOLIMNFIIOIDFJDLACFGBAMAFENBBCDOBFLNMLDKILFFNMBDIACMFLEAJEN", "LFMGBLKJLAEFNO", "LFFAKEONJMJOALHGCIJGDAIFDOIKGOIEMMDICKAKBNGFDDFOHNLAFLEJLBIIAIKJAIFNOHMLKDCGFMDJHILHNGO", "LFKMOHNHIDGFIOIGBKKFJNBIAFADIMICJIDGBMGLHFJDHFOMHMDDMMHEKMHINCKFJJMBLEKNFFOMGDGLHBJODIGJBOAJE", "LGHKMJKKDCIFLHMBIKMOAIANOJELKAACIFBHGHOMGOLLJGNLLEMHICELCNGJMNHJKNGGOE", "LGKHMNHAANBAOIBFBECHCEDICBJMMODDEFDOF", "LGMFMIBLJLMIGEKCKHKEABGGBHBMBNLIMFLDEHACABCLJBNOJJBCACOMIFFEEKBIMDHNNKIBLDICBID", "LGEFOKOIKKAGFMKDIHFHMDIGOEAHIKGOEHKAFHHLBFICAEGGMGJGIOOJFMJKEBGDGDKIA", "DEOGMALJDBHCAKODLGFFMMMDJNOAEHDFCAICJBMCNNGNCDKIKHAFCCOBAGKNOLFEGBAOJJF", "DEAHEJMLCKMEGGMMGCIAHHGHNFEEAEJGIAOKMGNKALJBOHALAMNEDGCHMFI", "DENFFBNGFLLMLJGMBIBHGAFKMIHKHBEFNIBKIDJAMEEOMGIEMFNHGEAHBELAOGIBKJAGD", "DEDJJEDNLM", "DEELHGADGMKNGJHBJJCLEFANFOJDNGADLAELECGMLHBKMDNAFJOFKDDHINIENMCOCGINJDBLJOGOOJACNGLFFJNGL", "DEKEBBIONALHLHKHJAIIMAHGKOAHBGBDJCN", "DAICMCNNHKKOEAIEFKCJNCOAEDLEMCKADLINJFEIGOFNGDBILNDFBJAELGENIHIDDHNAIBOG", "DALGAMALGJDHHDGFHLKMONADEGMJJDCHMJHHDMGCJDAGKAAJOJLDGHGMFAACENAN", "DAMDEGIFNJBDFKKNAIINJLOMFIMAEFNCFEDMFAHNBBNOAGEGCOOLFKIKGOB", "DAHMBIJEGNEFMAAOMFAKLOH", "DAHCJCGLHKCAHHCHONGGNJOAKGJJHBEGJCJDOOGLFHCDKFNHHAHMLHENNGMFGHNLMLBAKEBBKAMBJLNGEDOCC", "DFJOJMOJCHKJGLM", "DNEDJEAJMLONABICJJICHHEMGFOBEGKMHOCJIBCEOKJELMFIIMEFEEIFAHNDKJLJDIADJGMDG", "DNJEMFKJBFHHMNBAOBIOKFOHGLKINGOKEBKFMFMJJELEEKLKMKJCHOBOKIDHNLGIBGIADLOLCEOEBKONOFHILOFFO", "DNMIOADEIHHKIGOFAKINOAGJJIHEJLNMOEJO", "DNAKFMFIJKCHNADLJHLBBAADGEFCGOKDKGOMLDIL", "DBLHCLMOKDFHCLCALDJOOBFHMCHDNIIIBJHDCFNFJKIJKHDIMKHL", "DBEGBNMNKBEKEEFFLHADCCMMFHKEAEOBFFKAEHKBDLOMBBOJOE", "DBEAACNHOFFOODCMFMBKEBMOAOFGDJHAEFDLLEKNLMKCEBJLCIAKBIDKOLJFINOAIOBKHLJJOKGNJMJOAOOD", "DBEFFNLGNAKNKODOICANINHGMCEDA", "DKMDMKKIIJNNFCEGGJMHDCOEICBBBK", "DOMLBIJEMFDNAHIAKLKLKJIMEOCFNDDOJBLE", "DOKGICCBMAENHCGAGNHMKAAOLDAGIGGAEKOFILIFJDHA", "DOJBBGANHKCNHAAMGBE", "DOAFJEFNMACFFNMCDBNECBE", "DOAOGMNKHAHOLHMOEMMIHMIIMKKNEMHJIGLHGKLADELHHEIDNEM", "DODCKLBNMEDEELLBEKKELCGEJEDLACMCIOEJOIBEJHNEBJHNMGIALFBJBMK", "DDMDFKIADHAGBDICFCOILACIK", "DDCJOCLFECFMNMJNBLIOFAENMAEHCD", "DDGGIOOEHOBBLDJOHLOIDOEBBCJMCFNAOJAMIFDFCELMMCEKKBNINLCMEHIFKKBFNMHEBJOLN", "DCJGLKCAKIOIOCLBLHBEHKIILFKDLNCMGIOJCMJCIHFFKNCAHEGEKFNNCKGOMMNJHALNJIGLKILJFNCMMHAIEIHCNJLMDHDECD", "DICAMIMHHOKMOHHHLAO", "DIOADEMIIONKCLNJKABADAIMMKGAJKEDEGOLIFCNOKDBGKJJDCIONCFKBFFIIMLLABBNDNDGNHBFHJAOLGOOACKBFILOGGOIGBI", "DILEOCKMFMMLLFOFAGAMILMLHMGDACBEGM", "DIMBKKLNKMAHELBL", "DIBFOMNCCLHMDMGCFGNENLLLNEJOMJIMDHBHMDHIGHICGNAAOGBIDEKFEGOLBKJFJOAEHBEABDHDOMNIIKOGGOJJMCOCMKM", "DLLCDICDBNCKALOMCKJCGCOJGFOMMEJNCCNGLLGBBLCDOH", "DLKKGNOMEFHKFMALFNGJBNBNKJJKBCOFKBNCLBDGIDFAGNOLAKM", "DHFGCEFKNKKOFFC", "DHFBDBBBMACIAGHJHFIEODOHD", "DHFLAEHNFBANIGNGCCIJDIJBAMIJKGNIMBJLFBBILNBFNEBBAHKNKOAMJNHGLKO", "DHLGOKBEKCGAOMECLGCNDECAJCLIINHMMNCJNGEBLHMJADDJLNEJLJFFMEH", "DHKEKDFCODHDMDCFEOKFAJNOFEHLO", "DGHBNEHLLGHJLBHCJOEGMOCCBEHICHNENNCJKIMMDEOK", "DGMIACHAGFMBGLEIAMCIMONHNFDDGJEEEOKKBGEB", "DGNCFOILDHAMIEADFDCNKKJKJNLGADIAHHAOGDACMEEAJEJHCO", "DGDIMNMNNEEJLIIIJEIOIHFDLADHHHIFKFB", "DMNAOEOEDBMBCFGHMGBHKFJLFAKMACNLIGBMGIDDIMOLEKGFGFOHKFOKKGKIFEIDNDLAHKM", "DMHNMDNNMFNMIIGBGHECEDAKONJDILJBDBDMBCGGNBHBAOLMNFOBANCHIOEFIDJGMHLAAHGBJGKICMFAJNDJBJMOI", "DMMONNFKFGEIADIMFCDONLIOJLGNLINDAGGJDOOAEDAHCLDDMNNICJHAONOIEMFDL", "DJMHIODHNCFOMMMGGDANAHKBCJFKBNDJAHAAILNLEMCOBGACLJFLCDIABMHNHODOHHDMDGBLOOCJJAJKJLNHNLGEAKIOCIG", "DJMEHCHAAHLJHCDIOIMFGJOENHLJOEABOJAJNLGJIIMEACBIEOJAOEBCIFEOG", "DJGHBAMKOKIFMDBEFHJLDJLNMLDOKAJAGKLMGKKOBFGNOIADJACNAOCJECCNEAOKIFCMIIBCDMIALLIKGDMBOBAOKBDIIE", "CCCKNDDJHJHLIAFOKFNNOMGHOFGADALAMNNMMMBEMKGOAHOELGLCNNBL", "CCDJBGBOANKL", "CCDEDJEKJMCDDJLHHCEMJLKAHDONCHNMABILIFHBILFEOIJAOMGFJMD", "CCMMNNEEGEKIAJLDCCFLDBKLJHMG", "CCJKDBIGAMFNHJBLCOIMLJALIEGNLCKEFFLCIGEOCAJKFBDNEJBHODGJMBFLNNNOBKDFAFHAACFBIGJHJOMOIHCFABMJNLI", "CFKFMFLEBMHMBHHGCGDDFACFIADCKHIODGKNDI", "CFGIBJJDEIFDGFBHBFENNCAGLCJJLOILGJFJKOIAIFNNG", "CFBGOMFGECDNMKMAKFOJMGEBINBHLLDKOEKGHLKBNHANLNCHDDDJGMEIGLENDDHHEBIFDIHEBBHJLLK", "CFINJKKMIEJGDLLFBOHKOOGLBNKCNCGBOILADAFLHIJJOJKGIHJBCLEDOOMDCDOELJJECACKLFJMBH", "CFLCCOGCGLFBLMONAJJOEABDAADLJCDDAADCCFHAHGGJEAMMCKNBBBCGFL", "CFLMMCGGHJIBDAHEKGNNJCCIAJFCKICGJNJBKBKAEENIAAFMBMGFGBINDMDIONBFFFKGKANO", "CFAMFNCJAMMILHGMKMLFHLLCONHNMOLHMGDHKFDMCCKHLLILNKBKGMBCKGH", "Contrary to popular belief, Lorem Ipsum is not simply random text. It has roots in a piece of classical Latin literature from 45 BC, making it over 2000 years old. Richard McClintock, a Latin professor at Hampden-Sydney College in Virginia, looked up one of the more obscure Latin words, consectetur, from a Lorem Ipsum passage, and going through the cites of the word in classical literature, discovered the undoubtable source. Lorem Ipsum comes from sections 1.10.32 and 1.10.33 of de Finibus Bonorum (The Extremes of Good and Evil) by Cicero, written in 45 BC. This book is a treatise on the theory of ethics, very popular during the Renaissance. The first line of Lorem Ipsum, Lorem ipsum dolor sit amet.., comes from a line in section 1.10.32.", "COKFMOLOJHOJJLANBDE", "COFECMHIEOJML", "COCOMJFLOMANBBCKCCICJCBC", "COCMJOILICFDHNKLGAINFCNAELEIEMDODMD", "CONFAHEGKJGKGJMLFJOBDFLFJHDFJCIJFLOMFNHANJDLBHGJGOHKFKLAEENJAGDOHDHMFHOKOGBGJMHGEMMFJJJICLNOFLO", "COICMGOFNOBKLBNNIODCKMFCLLCBCLJBHFANDAJNBMCIFBIDIEEKFMGAOILBGINDJOIIBOIDAIILBBENGCAGENLKNNCOLKMKJFH", "COHEJIMHLJNOJEKEAAFKOFLMHGNCKNINCLIDDCGFJDMHNEHHCE", "COJLNCEAKOKDFICOINMCLKJGBKDFND", "COJEHOKIIJGBMDFHKHKDBNKDOEFNIGIAMLEOLDGDOHNAICJBEEMNGKOILMDHJONHOGCDBKGHHMEJKMLHEJOIDHGNICN", "CLIDLCEBCNEGIHHCOIHFOGKFKHMIJOLHGCEMBJLMMAENO", "CLLCFMILJGCDLKFOOKIBHGNDLGLJKJLIIGAHMADBLINAILGDO", "CLBINIDAOKAGFGJBBLHDHBEJBLBNLKCECHIKIINFELKALKOMCJIOJFEGNCHIJNAKHCOKIOLDLAMMGCLBFJB", "CLEHKHEBGOLGDLJDHGM", "CLNCFOJLNJHBGHGHJKKCNKGAJOGJB", "CNFBENKELGNDHJFFHHNIDABMLNMCKKCMDDDBLFJNCGAHCCLCAEKCNGNMENOGNLENKKLDGMHFKJKAGFIGFDILNHBHAEEBGBBG", "CNJFNBLHOGMFFNCIJLJD", "CAMNEHFJKFHGINDNILEMBBGNCEEJDFGBEHDMGBNLJONJMCHIFLEOEGEOEMCFJLKNGN", "CAGACHCNCHEMAIHNFINGNFMEDJHNNNHHFLGMOCCOEGDINAKCDIEOMMMBGCCKFMJDMOEJJJGKBNDCCHLOGJ", "CAALLFIFFFBMDAADGGEMGAKGGNDCGHHJAMGOIHJBJMIAILMIAMECLEFNNJOGHMFGLHFCNHB", "CJCEIDBJJOAFACJ", "CJINAFCJNHNKINMDLHIMNGFOGBFGJEAANKGANOOJDMNMENOMLNKKMLHEBLEIFOJCEN", "CGACILABCHBMOCHNMHHEKMNLMMHDLFMOAHNOLKFE", "CGDHJJMKGAAOIFKKEEDDB", "CGOMLIIHEKCFFNKEGGAEHNHKLH", "CGJBAFCNIGMEBFLMD", "CIJMFOMNKLGMDEHDEOOFONIMHJAGCIIDCHBFJJEFNAOA", "CIEDAFLDEMMAOCH", "CIFLFOJMMIGNJANEBDAIDALHEBFGHGECHECGHGGEICMDHCMMEJDFIBANFBJAEHJAIJOHLDAMEAKAJIBAKAKGFFCCOAGDIMLJBAD", "CKABHAMICENLHGNMGJHNBCCJIOELINBDLCHCGMHCHODCBDBDINDC", "CKBKENBKEELJABILDMHHNONHBDEEHNBGNGOBMNFIJBEDEBGBKCMNJALLONLONO", "CHMBBAJMNGNLGDJEMFEIGMNILJCMLDLJBOJEDK", "CHLHFEFLEHBKAJGOLJAAEJMLLHDAJ", "CHFCDDJLMIOCBDIK", "CEDINLFBOIJJBANDGKCLIHDBKFJELIMCAENBJKGIFLAIMMGJJLFBK", "CEJOKGOCDAAGLEGAACHICFKFOCEBBKDFALFOCOILKBAMOLADM", "CEJKCGEFNNDKIHODLBBDDJB", "CEOOLLBIHMFIJEAOIHFAOCJJECLEECHOKMIDOOKHHCOKBGIBCBKDNKJMAHABLOCCJIBMHDAKODIIFIKAFMBJIG", "CEOHANHDBDLLHAMFIKENIFJNCKKGODIDHCAOHDOAK", "CEBJJMFNKELCIIJCOOBLLDIFJFFNJONDODGMDAECOKAJDCBADGDIINIIFLLJGIJOELM", "CEBAIOANMELADFBGCOHHIMJDIBDL", "CEENDIINHGJKCDBOHAEAEMGMLMIIEJMNNJFDGNDGBGGFDNAC", "CMDGLBFLMOLHKFDFIBBMJFILDKILBIEIFGODIGLAMOKCLHHMMBKCEHL", "CMDHJOHOLOBFEAJKFHKIBHCOKADKKFEIJIFLGEAJBJ", "CMHDIOEGMLDDNABLIOBKGIEOLEBJMEOCIJGNIFJEAHBBGAFFLFIKFFIFMBGMADHJADACKEENOJOHFNKGFAH", "CMOBNMDMDDDFDCLNCEKCBHBHHBLJNMKOOOAKNIFGKJOGNKAHONOBLONMIHDG", "CBIDIHLCOLAGOFBFKOMBHNFHG", "CBNDGGIOLFLABGIEGKNGD", "CBECCKMNNJMACFCLJIJMOEJFCNANLAFIFIDNLMGKJLAINGIIEJNJCAGBIBDBNEEBFMHLJNGMMAFFCKJOII", "1 other", "EOBBIICDIEHIFDAJMFHDOBFBHIMNGAKAMEOFIBIGHOCHEOLLOOGFHBDEOIEKFFEMIOBJBDAIIELKGNGGEI", "EOCGNNIJCAOBKKAHGJHJKOJJGBLIIGNGKEKILEDJANL", "EOFIKHOKKEHFDDFNEDDJACDLDNJGBCADKGAKGDONBJLNLIBMHFGNA", "EOFECAHGJAAOCFMMBOOBEJCJIOIMFLKBCNEIBCOHKBCFAAEHBNIKAFCOGCDGLJKFNH", "EOAGJMAODMMJN", "EFOOIOHHBGCHKIDFCOBNHOGKEDEGEGO", "EFGEBDIKNCMKCLLNFKCKKNDAGLNLGHNABKBEOLGEBEJEDAOHFANKNI", "EFEGMAGJDJAODMKHJFMKCLACAGDMJMCLEAJDHMIICACMDMNMGIGGDKACKCCMBEALMCNKIGHCB", "EFNBLKOLNHAJDJBNDLELCOLEMHEDKFNKJMNBLIDMGEJGMFCHCLDHHEGCMNJNMFDANGEINKADIKBNBKN", "EFNINALBCHKMDOFACLIBJD", "EMEGEBELFIFI", "EBFDJHFLBCBLCBAHKELLMMJLDMCOELJJAAKDLFMDCLBGMDCDFOAHCBKCBHCFBAHLLFAIAKEBAN", "EBACHJICDFMICGDJABIECEOOODNJGMCHEJAJEGFDFMBEHEAAHJLMJNNGOFBMDMEEGGIAGCJFOLN", "EBGGEGANJDJCIKCKGFKBJIBCBMEMAOHMLIGGBHCDFHFFONMFNEKIDDFDBDHLICBOONMLHIICMAOOMDJ", "EBMHIKDCNLKCHLINLEGEBDCBBGJLNKMJJMOBHDDIIJGGMCBCMLMHFBJAJDDNLBOMBALJCFA", "EBDBMBFJDOCAMNFICNHDNIAJDKBKHHGH", "ELGOHIBMIDGKMIHCNDKGMKHLAEDEOAMMENKNDDNEBGGGIJAODOMBHKMNGGMBNEELLHCM", "ELDEBOENIOCGMCKIOAFJKOHAGEKDDNOLCOBEBMKLJNIBKCNHOAKCAILCKKEMMOKLBNOGEIHCFAHHJJIG", "ELANCFMNEHOBEONOGBDFJIBHBKHMJFJJLIAIAMLNCCLI", "ELIACLAADONHBKBMFMIJDFNBKNKHECIHNICHEFELIEKEEHJGMGJLMOGIANNIBKOGLOOCJLCHN", "EJMHABFAFKLHMG", "EJMDMMCOHLDNCGK", "EJNNOFEFMEHBOOOKCNHFHILNGEENKLNIFNJBBOGOBFGHICEELLIMJCKKOEDNCNLMEIDDGMHCOHEHKBLEIJENOOMLLBO", "EJLADBKKDGODAIJLOEHKMGALKDFBBCNDDMCJBGAOIGFGECBFMEKNNDJOOB", "EJCDBKOIJNFGLINHN", "EKBIBMLCLAMDLACHAOGEOJLNBKKAALBNJEELIDIAECCGCOIMGDKILJMAOMLI", "EKMEODHBABLBBFEJNLIHNKCOJLMHKGELJNLKDN", "EKEBOEOOBKFIOJNHGJBENIDNCMOBMJMOCMMMDIBOGFOFEDCFIMANOBINNLFGJJBJKHJOFEAD", "EKJGDHFNGFFCLHDEMCLE", "EKODIIAFODMKMOECAGLLLGKLGGGIIILJOMFJIIOOAFDOLAOINIICEBLIKAFG", "ENBGDILBMLNFMEGMFIOKMGGDIDDBMJMMDOGJFOELEGACDOBCOKCBHOKDKIHKKCDLGKLIMANMIMJBEGFDOAMEKGHHJ", "ENBLINEOMCEHMNILJL", "ENBKKNJKLJLHIKJOAKH", "ENGCCNJECOGDKCFLNLGCLDHOCFMCIADLCKGONFICCOIFDFAJIGOCJLIELNEANKLLMLKLJFFFIEFFIJNBGFIMFEEMEH", "ENKJFCLHIFBNAOIABCCNEJHNJHJDNFMJEOIAOAKDJHNLMJHBHLJFMMNIKJHFKFGOJLBAJFFANBKGONC", "ENJEEONFDGKNHBNMOGGECMKBHOLEJJLF", "ENJAACEMECKEECFOKJJMAOFDCICBFBBODMHBODOFJMBBFABOLMDDDDHJCCBFDLFNHKINMAMNAOLN", "ENHIFBNDDBKKIBBELACAICEKBIMOEODDBMJKFFOMHFBNNBE", "ENDDEIKNNDCOIGCEFMEJDJHBLJGJKGFIJJJJJLIIJLCLMIIKIIDBDHHCNGOBKHOJAGD", "ENOMFILECMLMNFNACDJEIHKDMIHEGOAEJLLFOCEEKCFFNBIBNAAOAFHNOJCNLGCGMACKCHDIAOAEAGHJLM", "ECODHLAGAEONLGMILHGHICCAIALAKJMJIJLFJCBGEGHNGGCKNKLCIGEKMMDBBKNANAJEEBNOODCLNCNEGKLNHCLILEAAHIN", "ECAAGLAHFFJHCFDEANNKKMFFHBGEEHIKAJMFEGGDJLNGNDDALMFKMOOOIJMFOGNNFLBCCLOCHJCNBLFNCDBC", "ECHMALMEALJKHJFKNKAGCAMMKGGLDKKLOBMNCLHDAFDBOJEOHLEEO", "EIINBLFABOCOCDCOHCCMCIJGJJ", "EIKDNADBBBLCJHMJILCOHF", "EIKDJFEMGBCNNLK", "EIMEAKDFNNMKDEHKCJEOINEOMMKFDEEFDB", "EIMNMLFJNNJOJBKGBGBKL", "EGHNIHBADFCABOL", "EGEGJDHDDICLOHEJFMFNJAFGBBJODONFJLMBG", "EGNJMJNJMHGJDOAHKOAIOHHHDHGCICNEOOGCIAHIGFMIDBBJAFNECGKMLACKFCAGDDKBJDHFAJKDKGOIBIFIEFIKGAGKL", "EGGLDKLFMFLOCMJCNNCGCJEBHOMLDNNKHAICNGCJDB", "EGLAOBEGNLCAKMHKJFBOLGOEIKDMLOMGLJKBIJMHHFKGGIEMCJIFIEJBJHCHGKHFEIMCOALHFKMADLHNBNIEINHB", "EHBAMKILLIMGKIACEBAJNMODJKIBMMAHFENDAOKALDDDAHHG", "EHNJMDFICCFAICKJNBGLBBDKICIJCGAACDNIND", "EHODDCEOMOENGFHFHOBGEMOIBFCOEFNGODFJIHMJDFKLCFHDHIJOCFLCKOELHIMNENHHCEHEGEACMLHMKEEAJE", "EHHNCFOABEENHADHABIIBCEGEDABGOJFDNBCJMGLIJMEHDEDFLNEIOGGJ", "EHHBIJJECJMBDJDCJNKAGOMGDGCEEIL", "EHGOILLHEFIGCHCCDF", "EHEMDOJBIHECONAHKBBFLHDEAHJFJBMNKFKMHCLJOAOMDHNDNIMMMBMLKODKENEICEGGDOLIHAABFMICAIDCCC", "EDKDLNFIFBCEEDNOCHHCD", "EDHDBKDAJJ", "EDLLGIDFBFEECDJFJDMBJNEGABJHDHMDGIACNONCIJJCGKGDEA", "EALLCHCEIGBDGOJFIGJMODFHMOKNDIKDDHJDCNFDLOIJEDKHLAAFMBLJACFMDKLIJKKMHDJEBBCFJ", "EAJFMDEDHIEHOOGHNLCFGLNOOBDCNLIAELNNKECHKIJLAKJHBJDACEAKHKNGGBCLFLILONIEFGODFO", "EAJEBAEGOHNJDGLICCCJDJEAGLOHLNNDLNAIMGEFECAHJFKDEFDILBLJAFLFMLCMAKMCIFCC", "EADOCLKFDEOKBLEBNICJKJCDOFFGHCJKFCCLOGEH", "EEEADDJALJHFEFMBNHBKDCJNCCGJJCEMLIJKDGJEBFBIDBGILFCLEFNNHGLFIFBBAAMOAOIMKJEGHACFOKLBMELHONNG", "EEDOJBEIFAOACNEEIDNAICOONFFFONNLCELBCIEAKHFIHHBBLBNB", "ICAGCCBAKJFKJKAGBICNDGBNJMDLBINFKCCCOGHJHKI", "ICLDABLJCHFNAIDENMIBMIKKKIACHNKJDCMAKOEAACKOBJDGEMHLAJHHDNDAIJMENNLONMFCGLOIOA", "ICNOOAAOLCD", "ICILOMNHCBBAMDKELODHLCJMOCBJABBMGIAIIEFEEDCIOHBOFCCMACHOJAFJKJBJMLNNOMDBMGOILNCNIKEE", "IGNCEAIEFKDGEFAJAKKFNCKJELFCDJLBCDEOFLAJKIAC", "IGMGOHBKONBFJECCNAIENACFGDKJAOCNHBGGGCKJMKOJONCLBOANAAGIAEJKFDLHGO", "IGLMJEOMCIGNJHAFOLJEHBCOGL", "IOEEHJFOKAFBOBFBAMOEAHNEFMJOHMFFONDMFMOKADKNACDGILAHCIMEKCGIOONAGBMNNJKGADBNLHLFHKFIBFNICEF", "IOGKEDEAIDKCIEHOOAMDMJMHMIFBOACKIBMBLBGEHOIGGEECBCOCMCANBINCAF", "IONKFEEOLGIKBFJDOEO", "IONCOHLLBBCBIFDGHCLMGDJOMCBOEGNKHAHCKHFNEHGJNBDLLJNDBOIJOHKBJMOO", "IOHBBNAKAKIFLOOKBOAFKLKNGEEGIDBLFKNAJGHOCLIFHCKD", "IOFFEEFFEIIOOMNLIBDDJKLGA", "IOFOHENOFOFDNKMFEIKDDLCGFJHHKDOMFHEBIJI", "IKJDEOFFHDLJHAKJBODMOHBCJFGMLNOAGDDKLKDFMOFJCMGNMFAKAEOFL", "IKJKHJHLMLGDEDAMOHNJNOGIGHDAIJIHAINGIBACOMLHFEGHHLBBNCBBAILAELOJJLKIOHGDAFADHKOGLEIMIAEJHDC", "IKEFKIFIFBMIBLEKJIO", "IJFIOIGNMCKDNLNEBBICCKIIGEJANGFIEIOAHILNAOKFFNMBD", "IJFKCKFIJFHIDGIFBFODDBLOJCINKNIKKNFKHEOFMDKAIIMEDFEBDIKFCDGNFIJFHH", "IJDNOCLKCDOIOBMJGKIEAMOH", "IJAGKEJCAMGIMLNHNELAIJOKHNNEKIHMKLHILBBOMCBJJBIFBHLKMAJLFGNLGCFJHIBBCBLN", "IJHOILAIAGKHJAKKONCLFJJDMGNGCNNHDGEBEOCIHKGMAHHOCNMBKDNBNKEFFIELEBFNJMOLCJICDFC", "IJKMIINCCCFHBEHECJGJ", "IJJIIIEMNELANEICKO", "ILLECMBNABBCMDJNNGOFDENBNBBHDKOJFLKJADDIMAJMHJBNOEOJNMMGFLN", "ILJCKGKHOBFCKCCOLKDFEILCDEGLIHNNHEKMMGGAOKNBMGGMCJKCLMBHMKAIOBKODNELMEGAHIFBNMOIDOFFMM", "ILGKJKJDAFELHNGBKHFCCDDIHBDMFJKCNJEE", "ILDDOGMFBMKHNNIMHLFAE", "ILNAHGIEDOCLBDGNNIFLOFMGBBCFLOIFDIEKIMHOICGJHHLGJN", "IDFDFDHMFEKBEHAIAGJIKKOHLJACFCAMAMHDKNLEIGABDMFOLMKKHJMEBJNFNMGAABGCNNLEHDJDDHGMAHEEGCBHLDCEFJC", "IDFJLMHAEHBBBAEGFOAJCHJJHGENNN", "IDLGDGGFMFECGJEMIEOGANKHODJAIMKDFMNHADFNBGFEMBAMKJHKOMOIIOIGJHKFJGICCLDMDNEDJNJOOAK", "IDCHDMAAJGMKGLBFLCLEIGFHEOOCGKLJNEFHJNJBMDOKEKGBKHACBBHJFACBBGBINIDBKGGAKIFD", "IDEMMJLODLJKGHCGFBCBL", "IDAEODMDNFALIJAFLBFGEBCNCDBLOJDLLBEGCKBJNJCILNINL", "IICFNBJHNJNGEFELEMIBOLNAGFHBLCAOMMAEDDLFMNGLKKIIJONNLJMKAHKOLNKAELMBEMOLFOGF", "IICBANGCLONOLKLCNFOBECINIEKMBJJDMDMDGCCJEB", "IIDNLFNEMLNDJAFKCDEAGIDMBENHFFGB", "IIJCCFLFGKFOIEBBMICBKGJGONJKNCDLOOIOKCOOHKLKNJCJBA", "IIJGALGCDBGGMLDOKLOO", "IMAKLEBAKCBGEOBLMNBAIOKIMKEIDILMCNMKAKGJOKEMLLHNNNIONDEGKBENLMCDLJIK", "IMKDMBBOEGLALOLMJFKGCLCMLNOHMCECBDCKJCCCB", "IMHOBFOIIJLDKEDCJDKLMGJAIHBAOGGFHHBIIG", "IFGGIBFFOKANOJOGOFBKKJLLKBLIFEMLINFCHJHCGCNMFOMHIEHFGLCCDKNKEGBOAFHOFODMKFN", "IFGAKNDNBAFLHKHLEFGBMEKBMBDGAAMIEJONLLCMMCKGJMIGOHGFOK", "IFLMHIJDGFLCGIFFLCMF", "IFJENIMFLLEGCBDFENAHFHNDIJDJLBCLLJNIMDEJCMDOMHLABOHLI", "IFJDKBEIFGNIGKJJOKLOJHCOCEFKJHAFCDNHNFCOAMBEHBAGJHDKIIJGFNECMJIGEGKEIOAMJLCKKHAIDIAMBAMHKHKB", "IFFOLKDHCIBOOLHLCJJIDAJCGGKCCDCOLBEHHNNCIBOBGGJEBJIIIFMBFDNKLIEONC", "IFHCNICGONGCCOFFJJEBJIHLJAHEMLBLKDBBJHJFKOAAHMJIODADJGLFBLHNNICLHNGOILHGLNKAEGCIA", "IFHAEGODGLKDBIGDMALLDGCJGCEOAGIIFDJ", "IFDDKEKGBE", "IEOOMGMHBAJDFEBCCOGCAFOJMMKHLJJEMAKOIGOIKNHFLAFCIKFIMDKOOGEMGAFFFNDDDHEIEEMFFIK", "IEGNFLKFJNCOMDNHCAHEOOHILLJBBFHAIEJEKNMDGBNELFOELNIBKFDJDHMJEMLGLKHHIKCFLNKEIKEJM", "IEIFCJOCHFEHDIMFLOEC", "IEAAHLLLJDJDDKIJJGOJNMOFDCBDLBKAGOALMEHJFDMELDBJMCJDLNIKHL", "IHFFLEKBCDMFDGFKENIAJCJAMCKFDEJGLMMDCBEJEILJMKGJFDHIEKLLEGFAANOLCND", "IHHHAGHKLIJK", "INCJFJGDECLMFIOOAJEIBBEEFJNDHDECJAADDNNHBJCCLIAJMKMLALMAILF", "INCCBFLMICBOOAABGHLBFGHCAOADAMBEKLOKEBKKJMNDE", "INHBGCLBDEGMJJMLBFBHNGNMKGKMIGHBDNHKMGKBMIGJC", "IAJAONKGFBADECFKBLE", "IAECHCDBOLDJBBOJOJCCDOKFHJMNMHFIELDGFNONAAEIGDHBCKDEAN", "IBDGMNFOHIKCLDDBLNKFGKLLGOILMJMMEMLCMNMOEFOKJNKOIIOEMI", "IBNEKMKLJCIGHLKCKBEIONMNNBJEEDIKFOLEGFGHJMBBFIIILAFOJFJJFIKFGNLICDALIJHHOIICCCJAFLIECGKNACHIBODCHJF", "IBKBABCMMOBOJCMJFILCDIJKOCJAHAOINKOELAFFJEECDOHAIFJFCINEIBGOCCGFFIAA", "JKINGNLMINNCJBJLHCIADGBGHICOACNOCCDCJFLOIENLFBKDECDGHDBEOMCCNLNOEFICNGIBAMCCFIGCKIDOGFMLJKHLEGEHLIF", "JKDDNHJEHCIHJJIKLGINNJMLAGCAHJ", "JKEBLEOCKIIMBNKKCGOMDMFBLIKHKBMNGHKIFGEODMKAFLKMIKBMNDBMGEDGFMDEHJIJODDHLLKEAMFFEFBMLMDOJOCHEOOD", "JKAOOMDBIAEMCMHJAFFCAEIBFNILEHBDMFCDILBLKFGDECCKMJAIJEDJABBDFJCMIHLLBKKJBJMJMFHLMMCFEOGE", "JMCOEMKJNNIJKMJMDLJGHJJHMHKNKCLECCJAFJANGBOCEAOAAGKDLGMKLGMGKLOMFGGIJAALEJKKNMFCAHELIHLINJOJJFD", "JMBLJKKEINKJJDOEDKJAAOMEHFMLBAKDICNNKACKDABEEIICHALFHJBMIEKDGJIEIJHAEAFLLGJDOBIGGJOCOHHAHA", "JMBCOJCBDAEOILNJGHC", "JMMODBGNBJLGANGHFOIGGACFDIKEGLANIBHJNNIFNGAOIICIHMBIGENONMOMANFONAGNMNNOKKNDHLCJJCOMNGBOMFHIAEGIBHA", "JILDDKCFECAODHGLOIGAHMHEGIDJEMMJOEMGMEFIENBIOMHOF", "JILNHLLLBMJJKADGKAJEFKGOLHKNMMLKDFGJHCECOEKNIHNCLLHFEINABIMEMNHKBFDJGJJFOILNBFMKLBOGKELMKLGAGNOJ", "JILKHNJOMMKMGJEDNMAOGIOMKFGEOOALJCDBILBCMHMMNADMMFFFLFGKNBFJDDCMELFAJEOEGGFBCLHDG", "JIJAECALEKHHEFCAGNEBMBDLICJHEDCHOOEONMMBFCEK", "JLACJFAAFCFKIIJINDAAEFDONDAIHIIMIFGOLIHIKBLFEBABNOACBMJAAHAIAAEAKHNOLEDO", "JLAEHDBMGNMFOEDIOIFEGJLMOHBOIBBHIEMDIJICAIJCKDGFAIINABHOMCFCFLGEJBHADNAF", "JLFFEFAGLIKGFGOLGGHHEJNBDEBOAEILLJNGDKNEFJDMHOODJMOGLGNHJIKHELNMMFBDOMEBAGBIIONJAIDIEIFMOGGMCNCKAAJ", "JLFGEDLDLLGEEDKMINACEMCINLDCCCGIBHBFOAJGEHAEOBHIHONEKOINDDGJCEFKAOMKIJJGJ", "JLIAGBHCKBNCBAFHLFOJNGGIFCMBIEDLIEBIICDIHCMOOEFOHIAGCFLMJDNLFCEBJAHKBFCMODCGNFIFEJMBENBHH", "JEKAEALEABADGCADHMGGBBADIAGMODJEGICJJBJDAKDMIHNODIMNIAGOGMNDIOFBOHGAGHLNLAIFKDCIDAJIMBDLGNHMN", "JEAFAHFDONFGDBIGMNHBOIKCCENBFGLJCHCHFFNOGOGAHDCNGGIBIIGJHFNNMGLCIAAHNGJLNBNGJNA", "JELMKHMHEIMAAFHADFEMCEIJBOGCEMAEBKEGJCFIBJMIHHEFOINLKHLEEFKFOEGJMNAEMJGKFAEEINLFMMCAOHMKFFLF", "JEBGIDKFEEBILHAMCDNKIEAFHMGOFKGCDIJDBMECMAFCLNJIIBLKLD", "JJNHCCFNHMINKHJEDFIOAGNJAOFANABHDACNDAMBACMHANGJLJA", "JJFDMAAGONFLLDOGNBKMJADMBFDLNGOMBNHLEHKBGCEIJHLDGICDLIJCAKCILL", "JJIKEFKHGLBOLLHJAJADGAKKDJKAEAJLDEJHDHCDOHFIJKEDMLGBBJMHENL", "JJANGMNANBHNJDDEJIJBBGHDIJKBGHDDMDGNBOJACGNBLOAOAGIKOEGMNFCCICCMELEMOABOBCLBBHHIIEDOJCGGNKLLEOI", "JJLJLODCEAGNCJBADGIFHKJGOEICBDJFMDBMLHMADGNGAGHJFGDECDIMAH", "JHBCGFKOJMCDMBCIKEJFEAJOKGHGBFFCLFJFHBMGNDIKGKCGKNHAFBCJMJBDBFONGHJALBDKOFFJCHNGALJDGLHJAOBLAHHHEJO", "JHNLGEIEBCHMMMEHKFMENNCKIFCLMENOGBIHCJBODLNJF", "JHLNDKDOFLINNEIEFGFA", "JAOFNMFIBLANIGFFLNHBHMLKLGONADIJEOILFIHAKLMOHEGOFMHGJAJNDABCOEOEFKMKID", "JABFJJCJKLKCHILMDIJIKNDDHLAOKAMJBBICKOMAKDENKDAGE", "JAEBJFIEODFFOIBCFHKFMOBEEKELEDCIIAMDOJEOGFCMKALDJNCMJCKINGDLBJAMODDDJNAGONBKBGGCIONH", "JAKNEHNMHCNBFCOGMEHNHDACMBFDNLIDKFMGHBAHNEGCMIMBGEMAJMMKM", "JAHOINLDOHFANBNOMDAEICFGMJKDHNMKABDDGIFAKNOFIFKLHMCLBDHFLMBOJOFGELFOMDHKFFNCHIGLF", "JAHAEEAKELMJNHJFNKMNEGJHIHBEELCIIEFKGIGHBHGCNEJEDMOLOJNHJLHLCINNECJHEFMEDKMNDFNIEIHMA", "JAIKBIEKLB", "JAMLGAGJKGLIBECBOJE", "JBGMDNENCNFBNIMKNAFKEIFBOBLFOBFNFAHAGGOAD", "JBIFKLNIKGEJKFAKEIKHDENBFEIICHDGABBGBEHABNDCFKEOAHAFEBHACHMFHAOAMBIKLLEEBDKLFMMMIFA", "JBELNOBGMODMBNKBJLLJGJDHFEAMECMMJGNGMDMBKNBNBKNDJBCNDAAKBLAFNDNMDFB", "JBBILBHBCKFNKGFG", "JBCCEAJKDLEMBODMKBAMHILGDJBOCBLFFCAODLBGAOCFINCELHCLEBHM", "JDGANCMMLDFANOHKJAEHODJOKMFE", "JDEGHINJKKCJEDOIHIOMHAOGIDDJGECHDJCHKCLFGMOJHDGEAACNMDBCLEIJCDEEIEFELGJBNJF", "JGMFGBLAECMOEJIEIBMCGALACNOFMEBOCIKEMGDDI", "JGJJGOELLCGIIEJCEBHDMFJBIMEAALF", "JGJIKDCEINNJIDOEAFCGKHHMEMCDCELJAJOHGDKDNFLFGODMEOJN", "JGFNOILEDHCLAJIEEGLIEFLOJOEICIJKHJGFIGMAKHFKHGLOCOMKFHDGHKFHOCHCIHHDEDCJKJKHFOOLOGHFOIILN", "JGCNIEKJMNLALFEGAGLHEBMCOBHMGEIMEHJIEGCILB", "JGGEACGEBL", "JGHJGFAEFAMDJGJEEILKIKDOHKBNKMNAOEKBEHMOMLCOBJEKIOBEIOJLOGGHD", "JCLEJIMNNJAKEGLBHEJBDCNDFKHEHAHDIGJNMBCG", "JCFEKAIGIKDANFOOOIBAFOOOCENCMEHAFJNEGLHBLHNAIB", "JCFNEAICKMHAICFBE", "JNDNCLAFCDD", "JNACIAEFHAJ", "JNHJDIJFLFKKI", "JNIIFKGNMKDALCNJIMNLMENKEJJGJALCAICGLJKAOKLELHKHGFADDCLNCCECMDGHHNFGGFFGMFFLGNEHLKHLMINIMFF", "JNBNNAEIJKCJHKNOFLGCLGDOFNAIKIFILKILGOENHBNODDKCGJDINNLKNLOBECKJANCGKEN", "JFKJEBJHGOKKBGJNOELAFLODBDNMHKNNOOFFBBKENGNBENLFOBDNFOFJGBIOKCDHKJ", "JFIGGBLKKGHLGBALADEELHOKBADKAOODJFBBFIGOMKANGFHOBDILFBBAAMFB", "JFIJDNCAEEMFFLJCMGJJFCGADAMDGNMODMMEMAMJJIMFMACKAAENKDBJGOFLO", "JFFMOBFFGCFIJFHIKNGNJJLHEHMNJJFOJKJDCHNAFGFMGFMHJIHEBFAAOLAIKBKDJBGKCFJMBAEELOJFJDLCKBOMKKC", "JFOOLCNCANJK", "JOLKOOFIECLDAKJKJJN", "JOKJGNMKOGGFEHMDFELLIJANGILIAKKDACDHADGDONMJDJFAONKEOLHJKDJIOBDNCJDFNEFKBFOBNKBGI", "JOKACMMADNEKFHLFEEOINMNNELCAAOGHODDJCFHFNJMIKKDMOHOLELFHBGEGCMEHCMFICNDMBOEGBHHDEIHCJENEGHOHE", "JOIKIIBHAOEGEEDCLLEFDDFBJAFJLIBFDNDLEJIGENNIKNHMLCKIJLAFAIKLKKKLBAGHM", "JONOGICEFIJGCNGJ", "GGMJGIMCDABHAMGLEGIGOLHBNILDGHEAJMHAANF", "GGCCBCBIDJBJLAHICHDAMNLJECNBOKIHMOGBFHFHJADEBIBLLIANDEMOJNKJABDHEDFMCAKMDIHCNGDOLLAIJLAE", "GGBNLKBAKOEKIFKHGAOACGMCNAEAONNGLKF", "GGNLAOKEGIMBHLHGCLNLEGAFNCMBJDBOFKEEDMJJKDAHJEAGDABKJLHNIAEMACNNABJJJJLEGHDBLALGCCFHOCBG", "GMCKJIJGFDIKGOMKFOEEACFKKKLKLBADCLLFGLDKOHLMMLBDOIMKMNFIIOJBOLCGNOFBLBDFODF", "GMDEIOJNOOMONEHBDICICHMACMKOGNCKGHKIFJCDHAHOFNKAFMMKDELMIBGEFLMIOJHIHGAICLAINFJGENCDNFCHKJGL", "GMDDIEMBBKEML", "GMGFCAJLLEADALHGLNHFMNEJAGHOCFEGDGFLICGDEOHJ", "GMKHDBGLLAJMAAIMMJMCKGNCNDOKAKLHHOGNEBMD", "GMOIELLAOMJEGKIGACMLEHLODMDJOAFHEHMDKNGFFKNBLAKIOCLGCGDJDADCGLHGMFGDCJKDIJBFEAK", "GMNLEINBAOMMGFNCJMEGOGOAHGFFEDKMDGFFNIHOONGM", "GMNDBJNNFFNCCCOFLIGNCILGLCIMNDGHOKMDKMFL", "GMFGNJJELCDC", "GMIHDNCFBDNOMOFINBFJCEM", "GMIMHAGHBLGMBADMKIDEFDKFLOMMCCCFFFELOEIJIDJJIBIINHGJGBFEBGFLKIOCOMMJEHMNGEFMKCLILMCIOCDOKBCO", "GJLJKBLMDHMLOKFFNLJCENAOEKLHBAH", "GJNMIGOGBLILLAKLDIFJFLKFGMMDIGKCEMELLJAIMC", "GJNDEJKCFMMEGBEOBBBKDCCAHAEHAACNIGLELDJKKOLLBAKLIADHDAKELBODNJNIGKIFNJB", "GFEIMFKNGKCHDMGCDFKMHOKOHOLIOCDJOOJCFF", "GFLBCJHINHNKNHAHALEHGGMMMDNDAJEIHCODANCAEEJENDNELHNGNEJALBHIIFEFCNAINCNHNHOCH", "GFOJIHMGHAMJHJHKBJOMEEHNMICIEBBAAAKNENDIGECCNCDDKCKDJBB", "GFKOCODOMLKLIFEOIIEBEJNLNCAGMHGIHEMGEMMIJHNDOIBFHFDCMHFDBHOOEEFABEGLEDKGCIGAHLLKNKFOJF", "GFDEDLCBMJAJH", "GKEODJMGJDJAKLGLI", "GKMGGKOGLKNFCELHCIELDEAHMDGOCDKNOCNKCIBFGBINDNHJNONCNGCFIOBAIEGA", "GKOJKAEIGEJJKAHMOMFJKAJNALODNCBODNJKEADIMCFNMAOMKOLMCCFEMHMBDEC", "GKHCHOIMBJECGEFHHIMDDLKKNFKEEADFLGCFEEANACFEEJOMJLLDHALLNDOKJONMJCIENLBLCAANILNMKIKGB", "GEOEFDOOMIKEKDGGHDMDIGCFAFHBGMLMFKEKNKLEIFBAGFEIFBIJNOJEFCOEKNNCFKKLAKGCHAJC", "GEKJDHJBGDOEJCEHKFLKFJDMKLEHHMKMOKBJMEEKLAJCFHDLHDGEGGFBLDHOMBGHOGGD", "GEBKJMGLBLLIEMMIAFFOMDAHDHEKBIBKNLLHHLGGDIDCFBIKNHHJLBNHNOIDECFLGNHJCBHKANKBFFLMGN", "GEMMBCHEBMOHCIIBHNHODBFHIAHHODJCMBLLBKGJBMGCGGAIEEKHCMALBN", "GEDDFFOFEMHEFCBGDAMIGAMFDD", "GEEMBJKLJOHFCMIANEJALKOFNAOIBOAHOEEFBFFFOCGHAJGLIHIBJLCBDFJEMIBBDOHAMBIANOHMMKOCNBIGNLLEFGEDBIFK", "GIEIMENGGKOAMNECLICKCFALIGJK", "GIBLGMGGMMHMFMELOAAMLIOEILDLDBGIGCILNIIGFHNCANHFMKBKBOOKFHGNBBMLCMJMLNE", "GICDCJCCBFJJILNEHJAACAIEOJKBLBOKBDCJGMNICAGDJHMAAFKHMKBFAOM", "GIGAFKFOEJIOGOIKCJKCDEBODLKNEJLNIGGJLICEDKMIJGJJIBHHNDOGLAEKCFIDNCIBNELKMEH", "GIILFIAOJKOHIIJJKHFGK", "GINMGMBDGODOEBMCIHOLCOLEMEKLFKMAJJEDINOJGCJGNEEMCJILACIGEBOFG", "GONKMDIOCGCJDGKNLBGMMHCJIEKOLMCDLMBMOBELOCJLBOHOACHIJILLCOMCKDGMIOAJDJJNBCAMFIBMKOELCEDMCBJNEGEOJ", "GOAELAHGGBNACGEIFANOJNCEFEOAHJIOAKEOKEFNK", "GOKBLKGKIBFALGEHMDIIOAJDLNHABIGBMDAILKHGNCKOKGEBIIINENBGGEMB", "GBMDMBIJBODHBCAMBFIDKLDBGFMFFIJG", "GBMEMLKOAOFKIIKIEIEGNFKBD", "GBKBIKCOEMNEHI", "GBJKCFJDIOFJHOONKAOLOHNFINHLCOJIAMK", "GBBBJGGLAMDGFHLNGIJCFE", "GBDOHKMJDOK", "GLNHHLIFMDBENJINAJIIJBAFGGDDEGJFLGFMGKJAGCIJFD", "GHEEGLBGHLNLLFHEMJMHLHDNLHFOAKOGMDOMCNDOFDLGEDDJBAOKEABF", "GDJJJLLNIIOINENIDBCNADMEBMIODDADABGOOIMKONJMBMLFEOMNFOOODCFONMJKGNBDMKD", "GANHHCFCFJAHLLAMBMLKKDCLGCHAKJBGNBOJAJNLLJIAGANIIDLIOCGLCFLEHKHGCHECEDOOJEMGNCLOIKLN", "GAOLDIBNCHCKDBENHMHNKOHOEABKGJLGOFILAEBGNHCBCF", "GAAJKKAIHKNKAKHHLKEGCEOBNDICJGMLBENLOHDLMFLGNGMGFKBBHIIAINJCAFGBHNKILIILKLLMDKGHNOKEFAHAAGINK", "GAAMGOBLENEIAAONFGFCINANNONMLLLLAJIK", "GAAIIAOEKBHCGHOOLFFOIGNKFIIOKAFKFGGDADIEAHACNCMKCOJAJLKJGL", "GAAHIIHHOBFHIMKEKEBDBMBNGIOHNOJEACFKJIOILENLANJLEMDDMDHFHMGLAFMGDEIJEJOJEDGLGIK", "GADIGNDIBFLKLOHECJKGMFAJOAKACNIBOJBCEJJJFBAMKKJNNJIBHJDNFAECOBGMFDNBCKCCGGILLKGOIENBFDBFLGGFA", "GALJMEINBEOOCOCLOJKOBHCBOLHDKCLGAEFIJEHNBOAACFIFHBLMKMLMCOEKILAEICE", "GALHAFCABFCBLIGHKGNKNLEAFGAALMACIOMMDDBGJLEFFAAFNHNEJCFCEJHEBHNEIEKCNIFGEJB", "GAJBECJEFHHOKGOFIFENKLFHILKFOIMKEIEMHCDIJBKDOFENNJBIAAALLFOEIHCIBCONL", "GCKGBODBIFFLGFJHJOLOFNLHOBCCCGOLAMJCJLOOEMOOFCDFNMLGOMKBNGLJABMGEACOGAIMI", "GCNIIOGMDGAHGKDOFDMBJIBGGLJBABAOFIMAECFNEL", "GNHGEMJNENLHCFOCCGFIKHJCBJFOGIMILHGCKGNHFFCAGNJEHGDDL", "GNNLALJHFMDMKOHNMNECEMDHAILOAAMFAKMFOJBBEEHFDJDEFEOFMFLLLLAEBLNKLGNLMFF", "line 2", "line 1", "line not", "lorem Ipsum is simply dummy text of the printing and typesetting industry. Lorem Ipsum has been the industry's standard dummy text ever since the 1500s, when an unknown printer took a galley of type and scrambled it to make a type specimen book. It has survived not only five centuries, but also the leap into electronic typesetting, remaining essentially unchanged. It was popularised in the 1960s with the release of Letraset sheets containing Lorem Ipsum passages, and more recently with desktop publishing software like Aldus PageMaker including versions of Lorem Ipsum.", "ADIFCNEOBLNOINHDKHNCBJFMDCBCJCMDDCGFJFOJLIJCDGAMLDFBMEMKIAOMFOECFMILHHEECJEHJDBILLIFHO", "AHJCCFAFJIE", "AHLGFKOICODCLELGOACJMJACEONGILJ", "AECLCIGOKOHGMLAFJEGOD", "AEHCADEKEEJMBJFHLKLNLLGJHGJFJBMKAIAKFILIAGFMHLMKFODAFMBGAFBKDHBAMKCFIFNBGHJLFNL", "AEJLDEACLMOGIJNAKJJEIEONCMJHCLGLFLDHFAOIADKGENBFEHMNBNKFJGNGOOMECMEAHENADEGGAKLLEJBKKNLAFENAO", "AELIIEDIJNDBLHBDGDGCNFFFGAHNAOIFBHBOFLADBDLIHDNMACJGHCCBLEJDBGHEFOFNIHGELGHLCBIDNLOOAGFKGH", "AENNMMLHLENGKEANMDNFECHNGIAIOHNHBAAKLLIMJCAGHJOFOCILMEFJJKKLOAAOGAEEOBLMCLNFOAIKGJGNJFFKF", "AGAHEELAMLIMJOLJ", "AAKBFNOENFCIDMMMEOLNDBBBGDGLGIFEFCIJCGKLLAHMFAEGIDNFHMEJNG", "AAOJBMJCJKNKO", "AAOKLNJDIOEDMEMCMHMBJNABFFKKOKHKDLLGKHHIIJLMHENHAKJDBDMFNA", "AAHEGBIMOLCDAMEEGNAIFIKEAHIFIIAMMOOHGGINKLDJ", "AABCBKLGCCGILFGILLJKKMHLGAMBGMH", "AABINGGILKAEBHMMFMDHKFGLBGMJKLMCJBGGBGNNH", "AALLMIJDDDHKHODHMMEKGHNNMBDGFEODHGNBFJEDCEOEFIKNKENAIHHFIBBIBKLAHHONIHICBOFOOINIJKAHKBBO", "AACOJFBDIBKHFAHKOJFNBLAFBAKMBBENKMEDOBLHIODLNDONKFH", "AAEMMHJKFEFLJEBLJDAIBHCHGIMHKLLGHHJMMMCDLOLFONLKIEOAIEMBIJLELCKJDLKEBBBNAONNAD", "AMCCGHEOAMGFGMHBBHHOCNODDDDJALBGKBAHBNDIDIGKEDFAMAGKALBKGKIKCNKCIFAFGMMID", "AMHENJBMDMKJBGDEIOJMELMLEIKKFO", "AMILNCMGMMOFEDCAJLHIBHJEAMIIDKAHHNCL", "ABFGBJBABILIABNAABJMGAFBFALFJMNFMBKOGNAIOCENAKBK", "ABKJNBCEGNGNOENMMMEJBBANDGMNIFOFIGNDMLCDIHLCFAIFIKNAFIOMBECICMCGJEDLANDKBICEMFNDAH", "ABHADBJGAOEDDKNELLHBFJBMIIIDHMEJGKKNOHJAGEDHGKLGDLEJOCBECLBKGMIFAOGEEEOBCN", "ACDIBBOKKKFHHJAHEC", "ACMBEAFLBIAONNJNKEDFGLALDBACGFEFACJDAKFGJFKHDDFMEGNNNHDJGONEOJMNGMHKHEHAGJGALLDHBGM", "ACBLLCBLELCCDHBLJJHMFCAJNHCAMHMALHGFIDFNCCKLAHBAFMHGKMHLCCOCHMMCGKG", "ANOEDLNEHBBKCHAHBBOJNCIHAAAM", "ANBMGDKMOMIOJOJJBLAGCABEKLGDDDJL", "ANBDABIHBLBHNKGHBDLCIJDHHDANDFCLMOBJENLDFCNEKEDDBMBEODCDGGOLFKFAJAOJAAMFDBJKCFNMLGMEHKIHIEGIOMNG", "ANLNOKBJNDKCEKCEKEBIIDHFICGMCKAHFFGOBEMELMMMDKFDD", "ANLAKCKBENFICECOMBAIAAKCAJLFDMCCJBBNLBHLBGLNANNLANG", "ANELFFGLKCJIDFLGDNKJFMFBCBON", "ANJMBJNBOBADJGENOCFGLOCJANDFNBFNLAIMBKFOOKIFKGDEIGLCDKAGJK", "AFAHGIJJNLAJAEJBLFFLCKFFFEAGDDOLAHAFILOB", "AFLAOLMNHCOEBCMHONNKAFFHFIKDLHABELIKIKFLNAEHEKFJMJBMIIFIGDEKGGJLNDBHNAIIDIJBKCCMNLH", "AFINIKBHBAGIIODLINHGCFKECJJHLLEJBHLOMMLDCGCAOHBAMAIJBNLAAMBGHKDILCHGAMNG", "AFNJHBLANHHBANELAEKKBEBJDMIFO", "ALMCIEKEFMMFLGFKIBNJNLBLGHMIBEOH", "ALJDONENILFJ", "ALEHABMKHFOCFIAIGCKOBEG", "AIBLBNKMEAKGMHLNNGBFIKNEDCJNMEOOBLLJCGGAJAJODLHNHEIHEEHHNECOAOMN", "AIBBOEOBGODJIJHIFHKJMCAIHJGHKIJEIFGJNJIAHHJEFEHOHCJEABAGMFK", "AIBADKAAHIIMBMDGMA", "AINKDEGAEBIJIMGENGEKDJFNDEMC", "AIAICBODCIKGMFFDBAHLMHBFKDFDKDJFOIBIICCONEFJOFJKLAJNOGDAEKGHGIDOBIJDEIKGLAHMIC", "AKOHOOKIOOFKKOG", "AKONLAJCNKLMCDMDEHIBEBIKIBJHIMKMJNGGIGKFGDJIOOGNNHIKDKNACMJGNBBHODAOH", "AKADMBFLKHOJGOONMHHJJNALEMDIFFACBEGAFDKAJIGKFLJECLGMJHJGDHOCEODDHHKALAHNMKBAFBBODEOENEEFFLIBDENJ", "AJOGAJLKHLLDDOBNAINAGLHOGF", "AJEEFHAFFAAGCDEEJHGBMHE", "AOMCMALBCCIGLBINJGAHMCCFGJFFHMECJIEEBBCLLKIMOEEGIFCNGBFNFKGEACKFKBODCLCHHAEL", "AOKKLHKJHHLNJEMEDAFKNELIFGOAMKFLNCHMLKGLEAJJLCENHECIKEEEAENNALEHNOJHAJFODB", "AOLADDLAMNMINAEOOLBEEMACKFDMNCILFKEOCFHAHIFDIINGMADMNKOGKFLEEMHHCDDMOEFOAKHGHIILE", "BCACOEJCKNJIMOEELAKIOCGAGALMJACDGIELBJCOKEDJMEJNHLMBMLNELHCOAMLDFDOG", "BCIGCDLMLMDFMICEF", "BCBBFNFDDDDNCHBHEAKCKJFDBH", "BCONJIHBCNGONJJBBLMAEIOBKILGOHNLBBJNJMLKOFENMIFCDJLKKBHDGDOCCAEEIFFJJG", "BCJHCNAFGEENAEGGOBJGGCKDNHBBLBNBMFIKANAHKHKMLFCNBFCLONMEODLNFJ", "BAGOMIEAMJDBNFBBCHGGMDFHGJFKNIEOKNNLABJKLKHOFAJK", "BAEJFJJKDCLNHODJBHINODLGDMHLIINGMECMLBKMHNHIJFDFIBEJBNFLMJNJKBOEIIABEJHOGIKHALKAELHGOKONADIM", "BALAHMIJBAFAMBMMDENEBKAAHABKKBOHAMHNOAFLLBBABODFHBOGBJKA", "BAKNHEAJJDBB", "BNAMJAAAEGBKCKIEAAIBEGBECKCNMCEIMAMEFBALNOIKGBJKDJBFOLGIAOMMDLAJHGDLCJMJCBJODCEJHKKIIILCNL", "BNHFLMCIIJIANFGMBMLMOFCJEIKFKMALJLODMNLNFNEJJDOOHMMDLOKIGGMMODFFHKODHLJCE", "BNGHHEKFBIMHNHKBJEFJHEJOLJNFHDOMKCBCMCKBOCKMOCNCBI", "BNGKJDBIHBFMCADGNGFMJCMKINDODKLDHMBINMOFBFMCBBMNCDILEHDNDIDCDGJHEGJBEMKDFAGEF", "BNJLAIODFLDDDDENHIDHMOMGLHNIOKMKANOIBMFHFJJBBFGLJEDOFBLGDHEICEFIDGKCMAIEOCJNIEJFCGF", "BDBOEKCEKGFLIFKDOMMJMIJHCMGLBBMICELAFMHGDBHEHFIBLKGIIKNECNMLMMIM", "BDODHAFJIDKNIKFCHGNAKCHHJKJHCOBDJFEHMCGFMFBO", "BDOENIAIANJAJMGLCNBMELAHCKGIBDOCEAHKFDD", "BDHBOOIMHIMOFLBHKCIFIOHEIDHFKEELNBKEHIKHHEHGCBJEHALNEKHCAENKDCJCBHBMAIFGBOJMJBFH", "BDKENFHJNOMGCDAJMOGNFBNFMDDCBBOJJEONMGHFIF", "BEOJHIHCFHIJNBDOLLNKAGHGIJGKMFFBMMICIJC", "BENIICAINKJOEADOEGHEAHAOGGDCLNECGOFHCDCGEFCDBGJCHIGMHAKBE", "BEEHCKAKAEMAKBFDMKAOELJFBABFFBMMMJDNKDFKFBILGBE", "BMADJGFLEMLOKJFCEBIHMGJOKNJCHHEKJICDLKCGFOOMIAKMBEMJMNIAFGMOLHONFKEGFHFJMGNAGNKAAIEKEOEAJLGLEAACNL", "BMFNBJMLAHGFKJELHJEFFGKANKGDMNOAEMI", "BMFLNCIFCHJGMHIJBBAOBHMGDMKLHLFDMFAOEIBABACHNKCKEMAFHDJDFHFBBIHLHBDMACCEAMO", "BKFCCIOODHAAFFGOGKMGEGAFFLCGE", "BKEEHHOHIANIMMHJJEOJGAAHMGBOFJCOJMENCBCEMDEJKIA", "BKIHJJDFHOFAEDOBIKHLAIMHCEOD", "BKNLHMMFKIOALOGJN", "BKNBOCBOBGKLCFBGLFOCLMLCNHLOAEEMLCIHGH", "BLAOIHFLJCOLFCNGAGF", "BLJCJIDGJIKNLMNHNOGHMAGNMGOIJLBIOJGHBFABBJAIFDDGHFDBDDDOEBLHBFCKLNEKAJILFEOIJLFECOAL", "BLJKCFBKJNMGDFABFGGLNLIEHOCALLOEAJFOMCKIFDBCBCGOEGNKCDDKHHDIIDOHLGKOHGFLMBMAO", "BLEFCKEJEFOFEIDLBFIDGNGDIHGKJFMOOALEAALMIK", "BBDNKBLEGGCCDOHIJOJO", "BBEBLBDDHFFOHLGLCCGFECHFABHHHDILALGGNBECKLACIFKMIBMMIMGJM", "BBILMGLGIMDEGBBFIAII", "BBLNOCOMMKMNLELOCLEKKFDKNKOFABMFFIANFGJIJGIAOJOLHCOALMLHNNBGMCMJBDFCGMJ", "BBFBFJDEDEHMCOEMDLMAOKJFMENAEKHBAHFLGCCGCFNKFOCGFIANADNJJDGFMGMEDHECBFMCLOGNHAOIGMJOOJ", "BOMGOCICJKMDOHIBJAMLHDMAAAAGJDNFJJGJLGGEGLKENLBIAJMJDJMNDEOAKNGMO", "BOMOMIOAAC", "BOCNNJHEJLMCFCFOBLHLNKHGOIJKJIGNMLIAANKNFABNDAOMAGBEFIJHHKE", "BOLMHBDABKFENKGNLIIKHNDCFMBHNBNK", "BOAKJBJOEFIFHEMAMCDCNHFHCKGHAOKLEFD", "BOOHALMHAOBLAMEHGJGIHBBLDCOKEIKNBCLBMLMHJJBKHCECLLEDDNKGJINLGJOGBJECKNBKOLMFGBBCNFLNNEEOGAK", "BOOHBFOEFMKKFKMEIDNFDFDEADNAFJJOELODGNKAJEEINHLCEMGCOC", "BHAELNFGIDKIKEOLHKACAOCKCHBONOHCGHNFKAJAELGCOBBLDOBJCGFJAGHHL", "BHALIDANFOMLDMIBNIBIHGMOFKMEBEELAECJIHKCOHNHOLHKGJGLFGLANJBMJDFOKHLKMHCKOJOGLBBFBNGINEJAMBKJBFJ", "BHEGKACDFIIJAJGACOLAOEDBCCJBOKEIA", "BHIKIFBHJMAJGMDKFACEJAJNEDFOLAFOIMHABMIHNOLGBD", "BHJJMIOCKNEKKGAFMDNFDNMGKHGNFEKDEKHNIACJIGGEMDFCHHHIABKMJDANOBDFFKGEDGBHGCJGGEJGNFA", "BHCODGGGEDJLEOAFNKHKKM", "BHCLMIJMONEAENFLGHGFKAJDEKLGK", "BHLCOOLHMKFEE", "BHLNMNDLDBGOMDOKJHGLLLIKEMEOJNMHCAMHCIEGCBAALIBOJHMKMFKLFMEII", "BHHOFMEHJKLCKEANBOOMMOKDBOGOCLFBHKCCFINBCOFMAGGFMBFHMNLJGFHBLF", "BFBDFLNJEMLJIGGIHDCBOIANHJAIHF", "BFGDBLOELFNONGIBOHEKNKIKBJHJCIEEDMFIJIJIMLKHGFLNNFEM", "BGCBHNLNFCNINDFKJBMFAODIIFAKEHEILDNMACGDDDODODEIDKJLGEGJKEJLBKAMNEKKFEEGFHMLFJHFCDBLNIMBHCEBLKNFGBE", "BGIONNALLKAOGNEFJIHFAFGEEHBMMBABOHCJDAKHICAHCEHLCBIBMOACMKIOLEIMLEEGBJGB", "BGHMOCFMKGOGBENGKOOHKEFOBJFKAKNHCHJCOGCAGLGECNMLAFFJFMFKCCHOMOGJJAHBCJLDDOKIKNHBIHFHLJNFLNBID", "BIIMONOAEKAEGMBN", "BIBNHJGBHKFKEMAGJOILMFIIKJ", "BIDMJANFKENKBOCGCGCMNJJFJNBCNCHOMOALHCIFAFKFMAIIKDJGDAMNGLNAIABMNHEFK", "BIGMFKCFDADHKBDJMCHLLOCOOIHAAHJAGODHJBMOLBCJIHBKHCHCECAOCHAICAOHBMFKDCGJJHDGMJBENMNILBILOFMOKIMLIM", "BINEOBDEJMBDMGOINIMDDNEEFBEGMDGN", "BICONNKGBOHFJLMILALMHCBNACNE", "it is a long established fact that a reader will be distracted by the readable content of a page when looking at its layout. The point of using Lorem Ipsum is that it has a more-or-less normal distribution of letters, as opposed to using 'Content here, content here', making it look like readable English. Many desktop publishing packages and web page editors now use Lorem Ipsum as their default model text, and a search for 'lorem ipsum' will uncover many web sites still in their infancy. Various versions have evolved over the years, sometimes by accident, sometimes on purpose (injected humour and the like).", "NMCNLDDLFCABBIDDONEKKJJDFLCHOGCNBMOEGIELKNBCNKNDIJFNEFOHIN", "NMECCOMHEKKBLEGOJFEODFDFFGMFJNINLGDABMJLDMMFHFMILEEABDKCDIKHCJOBLIAFKBBHCGMIGKKFLBNFNMHH", "NMEHBGGAEJID", "NMAFJBKIIBJHDOHGCMDFKMDNKHLMLAGOFHGEAMCKACCANNGM", "NMLHNBLGHEFJJLALAHDBMABJOKFJLFHMAJGEIBOJJGBDNIEBKEMBMMONIMCNIFN", "NMMIMJADMFMHIJDHABKFANLIFAOLDHJLLJAIHGFGMHLIHAL", "NOOLKFNOJIOHJMODMGKKNFEBFBAMBFIBFAEBDOEFDFOICIIFI", "NGIFDLAIJDHFBHLHDKHFLJLKDBJDL", "NGGBFOMANODOJNFHEAONNFDOBJFNEMJFMKGAJNAFEMEIHCKLGOKNKAJBKDMHEAEMJFICILHNOBEFFAOIEMONFEACOCNNDKJAI", "NNJCKHDHEKMAAKBNECCAOMLCKGFLHAAJEMEJEJHNMKALDEMAOF", "NNNHJAHIFLDKNBDLOLGJHHLMDBMOBO", "NEGDDFMIKFHIADNLBIHBLE", "NEIJOKIIEMLBIHILHGBNJOOBAAIBBBEECKLGNDADHCNIJMMDABFLEEHLFLLCEDJIALIMHAJNLNDDLMEFEIDOCDIE", "NENIMNIBMHNLKIBEODB", "NJJAJIFLIBBJNHBACOKFOHFHHIAIIFFEEIGLFHNONBGINEDLCGOMBBLEFDKLCJNIMEBHOIFCMGGMAIGGAFJCFJEOJKOGN", "NJEOAMNCBMKJLFALLHNJKIIJJFA", "NKBKDALDNMNDJJCNCECAIDNIG", "NLHIFMLGFHJNLFEEEJCCABJJJONJIDFELKBDAGCFOGBFMKDKAIMALKBKADHNDF", "NLOJAHOBHEMJFCBNGNEKGKMOK", "NLAACLOGDIKOGOBBNHJKFNHMEBJFOIGDMLHAKDCMFHEKKGGCCEEENKCMENMFOMECDBIMOIKGMDBHAM", "NLCLNJANGKLFALDJFNJIJMCOMBBHAFNHIJEKFBDBOCEEOFNCFFGEEEIKIKGBLHGEK", "NLCNFKLOLJKGKDDJLBHGDDKDADOGGGFIKEBNFKOBNLOJIGBOKL", "NANFIBAAKIGDHJCOGGGBLMMINJGGFJMNNKHOBFMICDE", "NAOFMFBJKEKOFMLCBNFNLJMAOCNDEANHOOJKADGEJINEMLMCNOB", "NABDHMANCJHCDFAGBLOEFCAGDGFBODECKHJHAMNFINGKFBKKKDHFJBFMFFNDKHFEOMEEBNOJFKIMNFBDHLELABECH", "NAFDIBHBNMBGCFFAKFAGBJKNOHFBHOCNLCOCH", "NALMHGIHOEMMEFFLIONKGJCKMJEBOHAFKOBADCOILOCMHJFJCHJIHCEJHBNJNGKIBFEOLKABKAEELDO", "NDOAMNMAMDHGLBE", "NDAAHBCCHDHGIEBKHCFHMOFHDGLIAACEECFLBEABFGCLGGEEKALBBKBNHAHGABDG", "NDJEGANLOCIMLLGHKCJIFGDCNDGIOICEGLMMNNAKIGDGAMCOOBMMGMBKHDODGHOBDNBOOANDJO", "NCLADNGLNL", "NCFMMHCEAGNIELJEDCIHKBEKJBINHAJOIDHFCEODLHGILLKKHANFGCHHCEIFIJENBGN", "NCGALMFMALACANBEOBLLLIBGGAEBIDBGEICELKMIGNAAFEJHKJDBBCAEHAHGGDIIIEMDKLBKNFDCMD", "NCEGHLBMDDNHIAOMBBDCEECKGIGKMFGOBJELNOHKGBFHFNDOBLIHOBLJADCMLNFOONFCFNMDIMDMHFFMOMNCLE", "NCKKDEGOAEHNKEHHFNGKEHNLBCJN", "NCHJCELBJNMABIANMNNNLBOLLOIEBDCCFMDFGFEMLHNGDOEHJMMCFKOGCJOFDAI", "NCMFCMOMKNAKGJKICJADOHOILCINBDLMGOGKJBNAKGKDMMLIDGHLGNFOLINIGCAKABKLNBBGLHMHENCHCDIMCLLFC", "NCOKIHLLHNIGMJCJFBALLIKMOCALKJOICDFEAGMIGKJAMCNDMKE", "NCACILNDFODOCLEAIGMENOFGGDFJOLNFHJ", "NHFDDEFGJDDEMGBACHDGJOHGKAIAKAACDLDJAEJ", "NHLDIIBECJOCLFFFCJGMLBOENAFHICLGLIJLHIKJDAGKJAMEHCG", "NHCCHNNINKNMCKGKMBKJEBNJFKJJCGLCAOGLHK", "NHHAEBONLGDIOLCGKMHIJDJCKCNMBDOKHBNICAOILBHIGNKIKNEHKJKKNMDNINAJIMNKCAMFLIEDMFCMDENIJHEIFGBFIGFBBHC", "NHJJNHFHFKHFGBFIMBMKBLOCGADIOLOIJFHCCFIMNNKOLAOCMMOCCHLJKFDBLMFACHLLMFNM", "NIMHOEKBMIHHDMDDJLJOBOBIEEMOOLNLJAINFCDFMBIDGMAIKCIJIMGCADIFJNFNCKIJINHOGGFDLDNNGHIIICGCFMEEDHJ", "NIADMNALGFMNNHDDAJOKJJJONNIGAOIDLCLKDMLAMMMOOGCOHLDHFGDAEMOMIJCLKGKEOMDNLIIAEOGHL", "NILLNHDJADMHEJKHBLGBGNMDAMMEGCNEOJDEHCAJDJMDAECGDEKAEIEHLKKEBIMBEBAAOFINOICIOCKOGDD", "NIINCFJFMJEOGBIOCBHKHFJGNMKLJHJFBFOHJGBMOCAFLCFLK", "NIICNLDJCCFNCNEJALKLOKEMLLLBHOENOBEBGHLEIJEOMNIHNFONBBBFFGFHKENLIAJJNINNCJMCEIDCAHOGEKIHOKNKEGAHHA", "NIJMGDCDIJGFEHCAMFOIKDNLCJBKNKEMLMBDNFGAEJALIOMNM", "NINCIEBAGOBDLNCJMNFMJAMNLDJBGKEFAIGICINDMOBACCKBLONMGOAKGBOD", "NBIDBEHFHCFIHMG", "NBFHGBCGIEKFLCHHLDAIAFKMJGDHF", "NBHNMMFNCCHOHDGNFAACAHOOLHMFFNLFMJDKDIKIEODCCDBCCHJF", "NBDKNFCMGHLLGIBMNFDOCDJCEKBLIOKMNLCLKBF", "NBDOIICNGAHBCBMHLGHLLCOGLBNAHNHHNAJKHLLEAHDALIGOKDFMELLAIEBNHCONILHK", "NBAOEIOHNDNIFHCJJEDFDLNEEAOLFGAGEMOBEIFFHNJBJKIDODIMGCAKKGNLNKJHGIJKEKHJNADFDNADJMNDGLINB", "NBACHJODMLFMK", "NBEMCONILEHOALOHBKJMOJFJCIKIHLBEABCCCBAEAMFAEHBBAHDEGINMNMOGJDHMBFHBMBBEGMGBLEHDAFJNGFEO", "NBEEDEMJAJBEOMLELBCIEDBGEDIBBHJCECIDBH", "NFMINIJMMEECLOHDMIJGFACLFDMGIEMAFLBFEOI", "NFJCMIFMEBMGHDDDMCDLNAALADIHIFKLDMDCNDHCMJLAOFNAKBCLFMDLIDIBKBNFNEEOFCLCFCOHJHAIJGKMCILOALELMFIOD", "NFALHBGFGJEBM", "NFDDHCJKEBHCLEFBEMLMLOCAJDKBBLCFCDAD", "NFDINONIMJIDHBOLFOCGHHBKDANIAFOHOLFBBNFJNJDJHAGIBHOOJI", "NFOGJNNGNNGNHBLHNCLNICICNDHNMDOGLEBILINMMDDLEOAKLANCEGENJOLGBEJJO", "FJAMNFIOFJDJJKAKMODCDEFAAMEOELCBONLOMKECCDJ", "FJBGAAEMCMLNJFBDMLJLGBKCNOEJAIGJCDAOAAKFIHLEEMGJDAFGLGKKODFLGGDM", "FJFKCMDIABFMHMCBKOADEOAJCELDDOKAMKFJNHMAHHFADMHKBCNNGJGNDJCNEECEGBDIOFGAFKJIMGHOKAA", "FGOAENIBLFAKJGKHJBOFINOEOOMMLGKCDBCAJGOMOHAFHAFKGIEHOBLOBKOFAHCAHMGMHMCLHFC", "FGCGOCNIIFKBALHEOLNMECOH", "FGCODJNCEFFFMMJFMGDHIDDMJBBFCBMKKJIOGOKLIGIGHOGK", "FGCONMKECAFKOOCNFADJHCMNMEEJEMHCHJIOMO", "FGHICGDJFEALJEFEHEHCFMNMIMGAEGINMDLFILNKOCINHBDEIEADFDBEHBMIFIKBEFNENGHJBDHKJCMFKKFABMMCDAMHCOA", "FGBDOHILKBECNKNHKJFJINJINJIIMAIHBNAMKJJIEDMFDJMIONBBOEFCICMJ", "FGMKCLLALHNDNKJMJEFDCKAGKDKGIIMMBEFNELLLNAMBNONOLBALINH", "FGIABLJKHJGGCLGBGCFMDGNCFIF", "FFMDGCEDJEJEJJFGJBGLL", "FFKGKFMNGMBFNEKNMBCHOBJOOKKHMHDGDMIBAMNGMLLEIJLDJCEMBDCDHIFIEOMFCMBOAAIFNCFAJMBIOHLAJGADEKLAO", "FFGGKDMCLLDOMFMNLKMBHMEMGICGIHKAMJLEJOCFCEAABCBDIFIMKDIKAAAJKCIHDMAAFGACGG", "FFLACEJCEIBKMADFBNEMEFFINDBMEMCEKJFLFCDKONAHDGDAC", "FKGIOIGIAOCELNOKEJFBEONBBNDDAHMCIICLGFKLJBMHDGMLGNCABGCLBKOAEDDDLKKCAEKDAKNMFDFCHGCN", "FKGDANNJHFJEEHAKFBLLAOAD", "FKCBODENEHNJJNONJGEBCMMKHGKGLNAHNFKINECGJJDHKDCJCAANLIOINCDKGDENAKHJGCGBBCKNKFGMAA", "FKMOMLEDMNNCLJOFNCEDFFDOOOKGEKJCFEFKLEKNEDIMDAFOFCDIHBAFILLLGNHBHCHEJJ", "FKJLHECAGFACIDCALBNMLMNHIDALBNOEOMGEGEHECODHDLHCFEMCIILCGGNLGHKJKMFCHEONKKGDNHBIMKHMLAGNDC", "FKAOMAJMGJKGINJJDAENLJHJDLIDDKFDCFICEJHEMLEACGIC", "FKHLFGACBGJABJHHBCIFGAGADHOFJOINEHDFCBALGDGGFCBIANJLJCKECAJCNBBJFKAGDKMNKEJIKMOBMOGEKDEHAENNAGII", "FNDBBKLBHKDDCGMGONLCGAIBKCNKAOFBENNKNGLMHEKCAEGOHHOLIJNMALHDGGIDCFJ", "FNNMMBJBIGGCKHHEHDCNLKCBEHCKCAFKNGGEFJIENFJKDJAMKEBFBAGDLFNLBJEADMKDNAFJHMLMCBKMKF", "FNLOKHJINMAIABCLFENIOAMBJEIHOGNNGBGHEHMEDMBCLGHOGDAHLACIHDBDGCHBDFEBKIKHMCFOKEGJGDLLFFCIEHCLKJNFG", "FNLGNMLIAODCMBEAGJEICOMNOCDLDEMCLLJMILEDCGOMGHOALGMJBLICMBOHGHICFMNBAEAENOJFGOGOCKKHFL", "FNLIBHLKHMCJEJCCNHNAGDGDKKAAKJGEIIEEIFLBJHDCNBABOEHKALC", "FNFDNICDDNEBIAJBEOHJGODIOCHKJ", "FNJKECOMHLLIMLOGNJEFINKLMNHAOFEKFGAADBNHMCCECMLBMAICGJALIDHJIEGKDONBFEJDKBJMG", "FNGEAHNOINHJ", "FIBOFHHBDAHEOBDJCFGJIBJDOEEFJINOEJFKICAOJACFNKGGIMCIBLCOAAKDMEHOJEEMEJFFA", "FIBNCKEGJGD", "FIBIALHFHDNJHNMLAKCAKLOFCGGJGBAGLBJHGFAGOK", "FILDLNINAMAOFDNHDJGKMNKMMNLJDMCBOMGDEDB", "FIOFFKCLCANAMLFNGECNKK", "FIJEADEEMND", "FIMLKKOMDHFCGCBFOGJCDHMFGEBJBKOAKKHKAEFOKHDDCEEHEALMJNKAFBCMJGBNMJKABONM", "FIDHFKNJKGLHKLHCFLBINGDMBMFEIKMDHCJOEFCLJOOADKKIDDKBJGGFDMHOILICHJGHJGAFHJMFBHNODJB", "FICBHCLCMJKBIFJODILFLKDMOOGDCNFJDAEELBJAMOJDKHOANDLNJHOLEEMKBJKGKAGHOFHHLKKCCOHNHNMMIKIINFMAJNECMNG", "FONCIKOBMMNEBGIMJLEONDBFAIBGDBLLDANABJLNCFCIGBDADICMNKCOHEF", "FOOCMNOCNLFFDKEJMFANFBOIOKEKKFLLDFIDNFDHFFGEIDDBKDBGBNLNEJGOHBKOOICOICMF", "FEOMCBDHJBBALGF", "FEHIHMEBHOGMNHKFFIKFNFECNDCINFLDMFAHHHOMJJBCKFODCKCJIJMOKGIDAFEN", "FEHCJKGEMCDIIDMHMJDKGBABNCNHMGJLONGNKFFCAIGFJCCGA", "FEKMIEABHCKCKOJMGJKOFJBEGFHFIJDFDNKHEMCDECKJEHCHKHFKIEOODK", "FEELGLLGEHJGCFJNGJCIICLOIGAFJFDLIADEGILHHNMCANKKHMJONGJLLHCANMJLBNBDJBM", "FENNEFIGOAJLLJBMOLOIFNNEBHJIOLKIAFJMNFCMNDOLLABJ", "FEMHLINIOKNEDCKBJFBICLOAHDLLODKFFHBGEOJABOHNAFGNLDHKIBCLMFLIGOCBIBKELKJBJLLCEFDMKKACCCNJH", "FHHJEOFLMOAKDFOMAKBIIDOINGHLMNCBAFAHMKGBIAEKKEJCOMFIAHDIOEFLAFICEANCJCBHJLNEKKCCKDNCGMKOOANEKAMNCE", "FHJIOBNBKIGOFMDDGMOIICGBADDOMHMABOAIMKJFHFHJCHJDGHGGMEJKDMLDBBLOEHJMLMCEKBGAKAHOFFJFLOGMOOBD", "FHJJDHCGACLOMNMNGFKBJFGFDJCJIBFCAKEKJECKHEHNHAOMHMMDKOIMNCBKAFCGDNDDDGCNINDBBKNNEBFI", "FMMGAHHMNKILCJNBFGBAFALBNHMAMKBDAMLCOODMMDOEJJN", "FLCGFIFODHFHEHAIFFLNNNJAAGA", "FLOAGBCECLIODCNJBLKFCIAMKCNHIIFNDEAFADFLGJOOGIGIAMGNDGMGKJB", "FLBOBGIEAILCMEEDELLKHLFHGCMAKGCDCMOOHJNFNHKNIDKLFGMLOJOEDIDCA", "FBCKGMILGAAEEJJGLINGLJICFEKBKMJICNBIHNIBJEKNHDKHIICIMIAAANOLDMMICBOOKNGDM", "FBMIJBKEEONOBCOCNAD", "FBJNHLAJFICJHIJ", "FBILOLOIKCNCADLLJMLCNJLNLBGEGKMKHLCMOGBHHJLOADMKHLO", "FDALEGELEAFEKHBICAAANELBODCLEBJOLIHKHCFFGJJMOCJHCAIIKIOMBDAMIDLAI", "FDEMIBJGMNNNCBIAEBIKLGCHAINEELHIMILFIJFBFJCKINNFFIFJFKGIHEBN", "FDENEGFJKFEMAKGOHOHDHAOGFODCJBIIENDMDDHEAAMCOFGKODEMHLDFDGLODMBBLCAGGKJBHO", "FDJGNOMMKABLGINFGNDIGCBLBNAOAEKIONLCIIA", "FABIKAAONEKNKCO", "FABLJOHDGINGIDAGLJKBEFMDNEIAMHIFLLKDLMMLOODDGADFINEGMIKEDCFJGCCOFIAKKDCEJENNIFDGCHHKFNOBHHHJG", "FAAJMHNJEEFKDLDHKJONJBNCBLDEGOHLBNDCCBBFICEAGHFJADANOAKKNCEOJHFLKCBKJKMNIAJDKJBLAKIJCIDJIOICFMJFIGA", "FAACLAABKOLCDBGDEKOGHABHMKKMFLFNED", "FANLILHLJHFDFIMAOIDOGOACEODEEAGGIGCIIAFOACECCJICLDHOMBOMHBBKCHOEKADHCHMFDCOJJLNGDGOGMHCAGGMDBLHJ", "FAKLMDJKAMCBNFIAENHAMLMKFBIFIKFNEGABAKJINOMOKEBLCDNEIDFAKNJKAGDEGECAOBCCHN", "FCJNFOFJMEKCAMKHMHAMILGJCLCOLFAKHGFAHLKHIKEFDFBLADIAEBAONKEFMFLDHJAJ", "FCMEJGINLNDDGFLLBEBGLGFIDB", "FCNIEKNNFODACEKMNFMBCFFBBMFGNONEFLOHKBMCJCOBOFHOFB", "FCIOHIONOJNBDNICFCFJJDNOCDOHBAFFMCLFJKNFKCDOOIKHBFMMJFBDDJJMNEGOBGNIIIJCIGOCIHGLLCCDNABMDOJJ", "MABGJHABEBAFAGLCCNKOMKDJFHMFLEDMDMBHGFCNKDOBNMOBGMILLLAOFNKDOIDFHGGIIKHHCELCHC", "MABNALHLDIIBIMNCADENMLNKBIICMBLCIKGKCDNKNCCFMEINCKAIBAGCIDOGEFDMIJEMCOLOKHBLOCDHDGEHCHNEGHADJGIOHDK", "MAAOAHNKJOKOEECBIBGMBBFJDJDBHCMNFCKCHLDMOGGECCLBDGNNMFJJBMKNIEN", "MAKENLOIOJEONJGMDIEHMHGKFKALCBIFDHFLBHJEGFAHMEGFHILDFOH", "MADABEMNCKFAFKJMIIKDFNGBHHMJAIJOIHCNHI", "MAJBIEECBDNAOEGMDMJBMBIKEGMEAOIJFGEGGHNKEALLAMAKHMKAKKNGAOJJKMKOA", "MALMDHGJLJKEBLINNEGFMCEKGKKBDMACKLAJDACNN", "MANNMBKAHDAKBMIDAGDJJKKBIJDMOFDMGGKLMLGENKBCFEJDJFIEFNIIBKABBNLOGIICGFOOGCCLDKN", "MJFJJCMDMKBDJDDJKJMNMDCBBFAIKGMJKDNBEHFJDHNBBEFKFICICCGBAJBOHBBGOIAODANKICGJJADHMONFCNNCODCAI", "MJNNFNNFIMHBFAKBIMDHNHMFNHAHIFOLLGFLMIHJJCEKDNCGCFLGLI", "MJDOKJGFECHNJEGAAMGLKLAAKKFJAMOCHGFHLAJBHLJMICB", "MELLCHFBKNLHCAIKOBIABBFMNMDCDJMNBL", "MECGBFCDONMMBMBHBDLAHDEKOCJCLLDIOFKICHGOMJDNLDCDAAABDHHDCADBDHIECJCHCFNGFIBDJBNFNN", "MKOAGDJDEOAKLKAKIDCBMHABCJBCBF", "MKNJAONINKLHDKMLEBIAFDLAMFMCOGMFKKDCHLFIJHOKCANCBMBDDOKALOE", "MKFHCFOFAOIELNKLCJIJGMBNJBBEGGLLMENOIKEMDDBBLNIMMHECADHB", "MKDKKGMBDEJAJHDDMLCFCMEOINMBJGCEKNDOELJEMMBHEILLG", "MKKAACLMFEJCNOJFMFOENFJJGCJMNHFLBEIMBJBCFBGAOFKJIEKOKAIAENLNMMMCCGOBILNEHHJFDEJFCMED", "MKEMLIDJLGMEGANNKDMHNACJM", "MLDDIDBMMNFAJGMHFCFKDMENAELMHKFGKEBEMLBNNJOBKHLAFAGIFK", "MLGAGAALAECIBDCLNGKMILHFOMJEMNMDONEMMICHNLJOOHJHDGFGLBNLNEI", "MLBHBBHFHNDNEMBMDGCNMJ", "MLKGBLOMLKCILGMOIGEFGMKFJJFGLGFAICBOMKBLNLCKNEOLMAKNLGFHMK", "MLKACHMEHOILLFMNHOLFNFLCBG", "MDBNBJLDLKLIBENHNDDFHFNJDKEDCBHLLHLHAIDFLMLCMFNCCKGK", "MDLLEKJDICJLKAJMLFNGGKILAGMOICOGGGOAFAFLOFOEDINJGO", "MDKJLBAJNKJONFELHIHIHBAOJMCFNCNHLIEHLLBD", "MDOKBJEFBNAJFIOCNMBIECAJNGALILIGFELOOABBLNJLOMJJKDGGEJNCKNIFCCLMEAFGHNGNEHG", "MDOEALOFCBGGJEIEBCDHOLACOG", "MDDKCDFJAAIHBGDBBLNOACKJGBGFIGDMHMKNNFBELCJKJLBLHKGBCG", "MBGFKOBLJDGMKJHCNHOJJCOJGKIBENLBOMACEAGCKDCCKCOFICLNABHGLGJFDKMDKEKBCKGNCLDMDDFCDNNBOD", "MBLCCNDECJOHFGOJLD", "MBMFONMAHOJGIFHCCHDEEMLHNMEBDJCEBAMNDOJOKOHLDICICGHFMBJAOKIJIDOJCKGJHJHBJEFHOLECGBHIBMAGEB", "MIDAIOMDGINLEGCDDGFAJBDBEDGOHAFHKKLMHBAFNIGDMDFIKNDAHDELELFINIHHJLBILGFIIABIGHDO", "MIIILBANKBCFNJMNKKEHECGENBGFBGNHFICCHOKHCJFO", "MIGOKOHFNBLEGAGDIKKNADDFHCGMGINMCIBNIGKGLIAKIAJICGDOOCA", "MIHJCILJFDJFKJDBKLBICGIOJAEGBMJAHMONOIGCOMMOMAAIDGINBMIDNBHLFMDEMFABGIEKFHNA", "MIHCCDJLINKEDHLMBKNGIOIOFAKHE", "MIEMLNCDECFO", "MIKKEHBNABJGCDCOHAKCCHJDOGAADKJBDAOACLMOLEHGMDOIHNCACAAID", "MINBBCIHJMCOBMKNMHKKDNADIALABMNGDJCAIKFJIHDADOALMMCJMOLEGKLCMGOAGDKLJHNAMKLLOADJCMKCAIC", "MIBKKAMIMHI", "MGCNACLNJAHADGCOILNJDJCHDJFGKALIBNNCJIKCJMLKBMJDICNGKFFCJBLBILBEIHGBKGIK", "MGBHALAOILGBILLGBBJADHNOIBKLGAFAFDBCMLICNFBOHIBLDMOJLOIMMDMKOMODIKOEDCAGLEAAIMNGEDNLFLE", "MOFJKJIHLLGCABLHFCOMFKCLBBKDDAEONCDOAEBEAOFMACKNJLOGALNCIILGEHNHIIALMLMLNOKLCJKGINLDIDJFNFJ", "MOJKNFCOKFKEFLACBOAIFHCENJAMEJMKAEIHJLGIBHCABEOECELIMHBMIIMJAHIJOLJIMAG", "MOJMJEMGKBHMEHHKKGLHIACAGLDGNIHMKFKBCGGNGKGDGOOHGLBDHLANA", "MONOGIDNDNKJDHCCLNOII", "MOEFOLLCFLAHCBLNMAKGLEEBLO", "MMINLJKNGAGLFEDJJFNEKICCGGCMOAEICCCGNEEIMHBNFBJEDHDFOHFOOEBFEIHLGIEEGFCOHEEAF", "MMJJJDHLHBAFCECCKANBECJLCOCHJBMFOOJJHJAEHEBLDCDMKG", "MMEKEOEDAIKCDFKFIBKAMBCINJNFDOHCNGKIBCGNEMFBJMCIFEJAEOMOJ", "MMGKFGJHEGAECGCDENAJOHLGFKIIFDNDOKLHAAMBFHBHGKKGJCCNDNLIMFOIHDGJJBMDMINCJDOILDF", "MCMKBCGOEFIHIBDODAKAOBAKHEBFCAEAAGNJJJHIKFDJLOCFBJOJOEJBCIBDJKNDFAOIKHGIECFANKEKDIFGJNCLLLGMABMONI", "MCJJDOJEMGBKHKHCLEMKLJOJMNJHFFFADBKMMHFECBJLBANNMBDDKGGAGCNINOBEOOHOGHIMOILGACAHJ", "MCJIMCAIMECIOCBFMKHFGCKCBCBJIOIKCBEGEIFLIN", "MCIBOJGHOCIABAFCJBFLNMHHAE", "MCEBDFONMG", "MCGOKCLCBAKGMIIDCMENKDFLEEBIIFNENJCFNHBGLHJCLDIKCIMOAHMFJGHGJDIMHMJFKMODCHFABGIAJAGDDMKBAFF", "MCGGEHMNHCDKEHOOJOAJMLELBKDOMAHJFAKBKHDOGIIOK", "MCLADBHDINHKOBNGFOBIEKKMBKOEOFENAEAGKIBMBIGCBGI", "MFAFEKHNCBDHNKNBHACJO", "MFLBHMKIOFKAFGEAENNFBHKNJOHIGODAKODFBEJBMKIDOBFALFHKNIMCLGGOBOCBGCGHIHOJJD", "MFIBAKKNKKEBFIMOJGKODNMICOBKKDEOEEIECDAINMALAHDGDOECCOHOLHKLBCILNAEEJOMEHNAKLO", "MNBIBAKFMBAJKDJGBKLKCIHEHICHHLJCHFDEOCHHJIMOAGFMHMIEOOIEKHMGCBFEONEGIOBOCAOKE", "MNAMBKGFJHBNMEHMECILECMOEKNBHFHHHDMLKBFDFIHOJADDNDOOHBNJEFN", "MNMCECCFHGONOILDKKBFFEMHLCCACDFCGMICLBGJAABNJGFFIBCNBAFFGCEJHGFD", "MNOCDACELBJDECMIA", "MHIJOIHIAONLJODNDGGEECKGLOEJOOMLDLMAEHILH", "MHENLGIFKNOKJBNFKFOOEIDAHNECNECNFFDHMLBNADIKGNLMLFEIKOEKCJNEGNJMIODKEJ", "MHGKCDNMGKBGKCODBJOIKIFKEMJGOLOHCMNFEANOGJLLLCGODNJOIEAADAJHGNNFGKLELKGLNEMCLHBFGLBHHENFH", "MHJBAGJAMENCAMDIBBLKFLLDIGEDECEAHHHHAKJDGBIJINODJEJNIKBDBBADKEHLAADHOKBIFBFKDGMFEDK", "MHBDMOOJHNKGLMJEGMNENINIFJDECHGHEN"];
-
-pub fn generate_lines(num_lines: u32) -> Vec<String> {
-    let mut lines = Vec::new();
-
-    for _ in 1..num_lines {
-        let mut text = String::new();
-        let range = rand::random_range(10..100);
-        for _ in 0..range {
-            text.push_str(&char::from_u32(rand::random_range(65..80)).unwrap().to_string());
-        }
-        lines.push(text);
-    }
-
-    lines
-}
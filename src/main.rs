@@ -1,131 +1,319 @@
-mod search_trie;
-mod lines;
+mod args;
+mod bench;
+mod output;
 
-use std::{io::{stdin, stdout, Write}, time::Instant};
+use std::{fs::File, io::{self, stdin, stdout, BufReader, BufWriter, Write}, process::ExitCode, sync::Arc, thread, time::Instant};
 
-use search_trie::{SearchEngine, SearchTrie};
-use lines::LINES;
+use args::Args;
+use bench::{bench, BenchResult};
+use output::{write_matches, Format};
+use serde_json::json;
+use cli_search::corpus::{Corpus, CorpusConfig};
+use cli_search::daemon::Daemon;
+use cli_search::http::{Api, HttpServer};
+use cli_search::{Alignment, Case, Error, Highlight, Order, PathMode, Result, SearchConfig, SearchEngine, SearchTrie, SharedTrie, TPEngine};
 
-fn main() {
-    let mut trie = SearchTrie::new();
+// exit codes: 0 done (for `filter`, some line matched), 1 `filter` matched no line, 2 invalid arguments,
+// 3 reading or writing failed, 4 the search failed
+fn main() -> ExitCode {
+    match run(&Args::from_env()) {
+        Ok(code) => code,
+        // a closed pipe, e.g. `| head`, only means nobody wants the rest
+        Err(Error::Io(e)) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("cli_search: {}", e);
+            ExitCode::from(match e {
+                Error::InvalidValue { .. } => 2,
+                Error::Io(_) => 3,
+                _ => 4,
+            })
+        }
+    }
+}
 
-    let lines = LINES;
-    // let lines = ["line 1", "line 10", "line 2", "not a line"];
+fn run(args: &Args) -> Result<ExitCode> {
+    match args.command() {
+        Some("gen") => {
+            let mut out = BufWriter::new(stdout().lock());
+            for line in Corpus::try_new(corpus_config(args)?)? {
+                writeln!(out, "{}", line)?;
+            }
+            out.flush()?;
+        }
+        // cli_search serve [--socket PATH], see `Daemon` for the protocol
+        Some("serve") => {
+            let default = std::env::var("XDG_RUNTIME_DIR").unwrap_or_else(|_| std::env::temp_dir().to_string_lossy().into_owned());
+            let socket = args.value("socket").map_or_else(|| std::path::Path::new(&default).join("cli_search.sock"), Into::into);
+            eprintln!("listening on {}", socket.display());
+            Arc::new(Daemon::new()).serve(socket)?
+        }
+        // cli_search http [--addr 127.0.0.1:8080] [--threads 8] [--input FILE] plus the search options of
+        // interactive, see `Api` for the endpoints
+        Some("http") => {
+            let trie = Arc::new(SharedTrie::from(load_trie(args)?));
+            let threads = args.parse("threads", 8)?;
+            let api = Api::new(trie).with_config(search_config(args)?);
+            let server = HttpServer::bind(api, args.value("addr").unwrap_or("127.0.0.1:8080"))?;
+            if let Some(addr) = server.local_addr() {
+                eprintln!("listening on http://{}", addr);
+            }
+            server.run(threads)
+        }
+        // cli_search filter QUERY [--limit N] [--format highlight|ansi|plain|nul|jsonl] [--engine engine|tengine|tpengine]
+        //   [--threads N] plus the search options, see `search_config`. like `fzf --filter`: ranks the lines read from
+        // stdin against QUERY and prints them, the exit code is 0 when some match and 1 when none do
+        Some("filter") => return run_filter(args),
+        // cli_search interactive [--format highlight|ansi|plain|nul|jsonl] plus the search options, see `search_config`
+        Some("interactive") => {
+            let config = search_config(args)?;
+            let format = args.parse("format", Format::default())?;
+            // the lines are read on another thread and show up in the options as they come, so a slow
+            // command can be piped in with `--input <(find .)`
+            let trie = Arc::new(SharedTrie::new());
+            let reader = trie.clone();
+            match args.value("input") {
+                Some(path) => {
+                    let (path, file) = (path.to_owned(), File::open(path)?);
+                    thread::spawn(move || {
+                        if let Err(e) = reader.read_lines(BufReader::new(file)) {
+                            eprintln!("cli_search: {}: {}", path, e);
+                        }
+                    });
+                }
+                None => {
+                    let corpus = Corpus::try_new(corpus_config(args)?)?;
+                    thread::spawn(move || corpus.for_each(|line| {
+                        reader.insert(&line);
+                    }));
+                }
+            }
 
-    for line in lines {
-        trie.insert(&line);
+            interactive_engine(trie.tp_engine(1024).with_config(config), &trie, format)?
+        }
+        // cli_search stats [--input FILE] [--format table|json] plus the corpus options, see `corpus_config`
+        Some("stats") => run_stats(&load_trie(args)?, args)?,
+        Some("bench") | None => run_bench(&load_trie(args)?, args)?,
+        Some(command) => return Err(Error::InvalidValue { what: "command", value: command.to_owned() }),
     }
 
-    // println!("{:?}", trie.list());
+    Ok(ExitCode::SUCCESS)
+}
 
-    let query = "ABB";
+// the options of `search_config` which take no value
+const FLAGS: &[&str] = &["path", "require-basename", "acronym", "smart-case", "greedy"];
 
-    // interactive_engine(trie.tp_engine(1024));
+// --path ranks and abbreviates the lines as file paths, --require-basename (implies --path) only keeps the lines
+// with the last queried symbol in the basename, --acronym only matches at word starts, --smart-case matches a
+// lowercase queried symbol in either case,
+// --greedy highlights the first occurrences instead of the best scoring ones,
+// --order score|lexicographic|insertion|length sets the order of the lines,
+// --max-filtered N sets the most ranked matches filtered for a longer query instead of walking the trie again,
+// --mark-start S and --mark-end S set the markers around the queried symbols (`-` by default)
+fn search_config(args: &Args) -> Result<SearchConfig> {
+    let default = Highlight::default();
+    let highlight = Highlight::new(args.value("mark-start").unwrap_or(&default.start), args.value("mark-end").unwrap_or(&default.end));
+    let config = SearchConfig::default()
+        .with_acronym(args.flag("acronym"))
+        .with_case(if args.flag("smart-case") { Case::Smart } else { Case::Sensitive })
+        .with_alignment(if args.flag("greedy") { Alignment::Greedy } else { Alignment::Optimal })
+        .with_order(args.parse("order", Order::Score)?)
+        .with_max_filtered(args.parse("max-filtered", SearchConfig::default().max_filtered)?)
+        .with_highlight(highlight);
+    let require_basename = args.flag("require-basename");
+    Ok(if args.flag("path") || require_basename {
+        config.with_path(PathMode::default().with_require_basename(require_basename))
+    } else {
+        config
+    })
+}
 
-    engine_performance(&trie, &query);
-    t_engine_performance(&trie, &query);
-    tp_engine_performance(&trie, &query, 4);
+// the lines of --input FILE, read like stdin by `filter`, or else the generated corpus
+fn load_trie(args: &Args) -> Result<SearchTrie> {
+    let mut trie = SearchTrie::new();
+    match args.value("input") {
+        Some(path) => {
+            trie.read_lines(BufReader::new(File::open(path)?))?;
+        }
+        None => trie.extend(Corpus::try_new(corpus_config(args)?)?),
+    }
+    Ok(trie)
 }
 
-fn interactive_engine<T>(engine: T) 
-where T: SearchEngine  {
-    let mut engine = engine;
-    let mut input = String::new();
+// the generated corpus, defaulting to the shape of the original sample:
+// [--count 1007] [--alphabet ABCDEFGHIJKLMNO] [--length 10..99|50|50~10] [--shared-prefix 0.0] [--seed 0]
+fn corpus_config(args: &Args) -> Result<CorpusConfig> {
+    let default = CorpusConfig::default();
+    Ok(CorpusConfig {
+        alphabet: args.value("alphabet").map_or(default.alphabet, |a| a.chars().collect()),
+        count: args.parse("count", default.count)?,
+        length: args.parse("length", default.length)?,
+        shared_prefix: args.parse("shared-prefix", default.shared_prefix)?,
+        seed: args.parse("seed", default.seed)?,
+    })
+}
 
-    loop {
-        print!("\nEnter next char ('*' to erase one character): ");
-        stdout().flush().unwrap(); // ensures print is displayed before stdin
-        stdin().read_line(&mut input).unwrap();
-        
-        let now1: Instant = Instant::now();
-        engine.query(input.trim().chars().last().unwrap());
-        let elp1 = now1.elapsed().as_millis();
+// cli_search bench [--query ABB] [--warmup 3] [--runs 20] [--engines engine,tengine,tpengine] [--threads 1,2,4,8]
+//   [--format table|csv|json] [--max-filtered 65536]
+// TEngine spawns a thread per node reached by the query, leave it out on large corpora
+fn run_bench(trie: &SearchTrie, args: &Args) -> Result<()> {
+    let query = args.value("query").unwrap_or("ABB");
+    let warmup = args.parse("warmup", 3)?;
+    let runs = args.parse("runs", 20)?.max(1);
+    let config = SearchConfig::default().with_max_filtered(args.parse("max-filtered", SearchConfig::default().max_filtered)?);
 
-        let now2: Instant = Instant::now();
-        let options = engine.options();
-        let elp2 = now2.elapsed().as_millis();
-        
-        println!("history:\n {:?}", options);
+    let mut results = Vec::new();
+    for engine in args.list("engines", vec!["engine".to_owned(), "tengine".to_owned(), "tpengine".to_owned()])? {
+        match engine.as_str() {
+            "engine" => results.push(BenchResult {
+                engine: "Engine".to_owned(),
+                threads: None,
+                keystrokes: bench(|| trie.engine().with_config(config.clone()), query, warmup, runs),
+            }),
+            "tengine" => results.push(BenchResult {
+                engine: "TEngine".to_owned(),
+                threads: None,
+                keystrokes: bench(|| trie.t_engine().with_config(config.clone()), query, warmup, runs),
+            }),
+            "tpengine" => for threads in args.list("threads", vec![1, 2, 4, 8])? {
+                if threads == 0 {
+                    return Err(Error::InvalidValue { what: "threads", value: threads.to_string() });
+                }
+                results.push(BenchResult {
+                    engine: "TPEngine".to_owned(),
+                    threads: Some(threads),
+                    keystrokes: bench(|| trie.tp_engine(threads).with_config(config.clone()), query, warmup, runs),
+                });
+            },
+            _ => return Err(Error::InvalidValue { what: "engine", value: engine }),
+        }
+    }
 
-        println!("query time: {:?}", elp1);
-        println!("options time: {:?}", elp2);
+    let mut out = stdout().lock();
+    match args.value("format").unwrap_or("table") {
+        "csv" => write!(out, "{}", bench::to_csv(&results))?,
+        "json" => write!(out, "{}", bench::to_json(&results))?,
+        _ => write!(out, "{}", bench::to_table(&results))?,
     }
+    out.flush()?;
+    Ok(())
 }
 
-fn engine_performance(trie: &SearchTrie, query: &str) {
-    let query_len: u128 = query.len().try_into().unwrap();
-    let mut engine = trie.engine();
+// the shape of the trie and its estimated heap usage
+fn run_stats(trie: &SearchTrie, args: &Args) -> Result<()> {
+    let stats = trie.stats();
+    let mut out = stdout().lock();
+    match args.value("format").unwrap_or("table") {
+        "json" => writeln!(out, "{}", json!({
+            "lines": trie.len(),
+            "nodes": stats.nodes,
+            "terminals": stats.terminals,
+            "max_depth": stats.max_depth,
+            "mean_depth": stats.mean_depth,
+            "branching": stats.branching,
+            "node_bytes": stats.node_bytes,
+            "line_bytes": stats.line_bytes,
+            "heap_bytes": stats.heap_bytes(),
+        }))?,
+        "table" => {
+            writeln!(out, "lines       {:>14}", thousands(trie.len()))?;
+            writeln!(out, "nodes       {:>14}", thousands(stats.nodes))?;
+            writeln!(out, "terminals   {:>14}", thousands(stats.terminals))?;
+            writeln!(out, "max depth   {:>14}", thousands(stats.max_depth))?;
+            writeln!(out, "mean depth  {:>14.2}", stats.mean_depth)?;
+            writeln!(out, "node bytes  {:>14}", thousands(stats.node_bytes))?;
+            writeln!(out, "line bytes  {:>14}", thousands(stats.line_bytes))?;
+            writeln!(out, "heap bytes  {:>14}", thousands(stats.heap_bytes()))?;
+            writeln!(out, "children         nodes")?;
+            for (children, nodes) in &stats.branching {
+                writeln!(out, "{:>8} {:>14}", children, thousands(*nodes))?;
+            }
+        }
+        format => return Err(Error::InvalidValue { what: "format", value: format.to_owned() }),
+    }
+    out.flush()?;
+    Ok(())
+}
 
-    let mut avg_query_time = 0;
-    let mut avg_opt_time = 0;
+fn run_filter(args: &Args) -> Result<ExitCode> {
+    let query = args.operand(FLAGS).ok_or_else(|| Error::InvalidValue { what: "arguments", value: "missing QUERY".to_owned() })?;
+    let config = search_config(args)?;
+    let format = args.parse("format", Format::Plain)?;
+    let threads = args.parse("threads", thread::available_parallelism().map_or(1, |n| n.get()))?;
 
+    let mut trie: SearchTrie = SearchTrie::new();
+    trie.read_lines(stdin().lock())?;
+    let mut engine: Box<dyn SearchEngine> = match args.value("engine").unwrap_or("engine") {
+        "engine" => Box::new(trie.engine().with_config(config)),
+        "tengine" => Box::new(trie.t_engine().with_config(config)),
+        "tpengine" => Box::new(TPEngine::try_new(trie.snapshot(), threads)?.with_config(config)),
+        engine => return Err(Error::InvalidValue { what: "engine", value: engine.to_owned() }),
+    };
     for c in query.chars() {
-        let now1: Instant = Instant::now();
-        engine.query(c);
-        let query_time = now1.elapsed().as_millis();
-    
-        let now2: Instant = Instant::now();
-        engine.options();
-        let opt_time = now2.elapsed().as_millis();
+        engine.try_query(c)?;
+    }
 
-        avg_query_time += query_time;
-        avg_opt_time += opt_time;
+    let ranked = engine.try_ranked()?;
+    let limit = args.parse("limit", ranked.len())?.min(ranked.len());
+    let mut out = BufWriter::new(stdout().lock());
+    match write_matches(&mut out, &ranked[..limit], engine.config(), format).and_then(|_| out.flush()) {
+        // the exit code still tells whether there were matches
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => return Err(e.into()),
+        _ => {}
     }
 
-    println!("\nEngine performance:");
-    println!("Average query time: {:?} ms", avg_query_time / query_len);
-    println!("Average options time: {:?} ms", avg_opt_time / query_len);
-    stdout().flush().unwrap();
+    Ok(if ranked.is_empty() { ExitCode::FAILURE } else { ExitCode::SUCCESS })
 }
 
-fn t_engine_performance(trie: &SearchTrie, query: &str) {
-    let query_len: u128 = query.len().try_into().unwrap();
-    let mut engine = trie.t_engine();
+// the options go to stdout in `format` after each keystroke, the prompt and the timings to stderr
+// so the options can be piped, e.g. `--format jsonl`. stops at the end of the input
+fn interactive_engine<T>(engine: T, trie: &SharedTrie, format: Format) -> Result<()>
+where T: SearchEngine {
+    let mut engine = engine;
+    let mut input = String::new();
 
-    let mut avg_query_time = 0;
-    let mut avg_opt_time = 0;
+    loop {
+        eprint!("\nEnter next char ('*' to erase one character): ");
+        input.clear();
+        if stdin().read_line(&mut input)? == 0 {
+            return Ok(());
+        }
+        // an empty line changes nothing
+        let Some(key) = input.trim().chars().last() else {
+            continue;
+        };
 
-    for c in query.chars() {
         let now1: Instant = Instant::now();
-        engine.query(c);
-        let query_time = now1.elapsed().as_millis();
-    
+        match key {
+            '*' => engine.try_backspace()?,
+            c => engine.try_query(c)?,
+        }
+        let elp1 = now1.elapsed().as_millis();
+
         let now2: Instant = Instant::now();
-        engine.options();
-        let opt_time = now2.elapsed().as_millis();
+        let options = engine.try_ranked()?;
+        let elp2 = now2.elapsed().as_millis();
 
-        avg_query_time += query_time;
-        avg_opt_time += opt_time;
-    }
+        let mut out = stdout().lock();
+        write_matches(&mut out, &options, engine.config(), format)?;
+        out.flush()?;
+        eprintln!("matches: {} / {}", thousands(engine.match_count()), thousands(trie.len()));
 
-    println!("\nTEngine performance:");
-    println!("Average query time: {:?} ms", avg_query_time / query_len);
-    println!("Average options time: {:?} ms", avg_opt_time / query_len);
-    stdout().flush().unwrap();
+        eprintln!("query time: {:?}", elp1);
+        eprintln!("options time: {:?}", elp2);
+    }
 }
 
-fn tp_engine_performance(trie: &SearchTrie, query: &str, thread_count: usize) {
-    let query_len: u128 = query.len().try_into().unwrap();
-    let mut engine = trie.tp_engine(thread_count);
+// formats a count with a comma every three digits, e.g. 1,234
+fn thousands(n: usize) -> String {
+    let digits = n.to_string();
+    let mut res = String::new();
 
-    let mut avg_query_time = 0;
-    let mut avg_opt_time = 0;
-
-    for c in query.chars() {
-        let now1: Instant = Instant::now();
-        engine.query(c);
-        let query_time = now1.elapsed().as_millis();
-    
-        let now2: Instant = Instant::now();
-        engine.options();
-        let opt_time = now2.elapsed().as_millis();
-
-        avg_query_time += query_time;
-        avg_opt_time += opt_time;
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            res.push(',');
+        }
+        res.push(c);
     }
 
-    println!("\nTPEngine ({} threads) performance:", thread_count);
-    println!("Average query time: {:?} ms", avg_query_time / query_len);
-    println!("Average options time: {:?} ms", avg_opt_time / query_len);
-    stdout().flush().unwrap();
-}
\ No newline at end of file
+    res
+}
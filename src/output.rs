@@ -0,0 +1,57 @@
+use std::{io::{self, Write}, str::FromStr};
+
+use serde_json::json;
+
+use cli_search::{Error, Highlight, Match, SearchConfig};
+
+// how the matches are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    // the queried symbols between the markers of the config, one line per match
+    #[default]
+    Highlight,
+    // the queried symbols in bold red, for a terminal
+    Ansi,
+    // the lines as they were inserted, one per line
+    Plain,
+    // the lines as they were inserted, each followed by a NUL byte, for `xargs -0`
+    Nul,
+    // one JSON object per line with `line`, `positions`, `score` and `id`
+    Jsonl,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "highlight" => Ok(Format::Highlight),
+            "ansi" => Ok(Format::Ansi),
+            "plain" => Ok(Format::Plain),
+            "nul" => Ok(Format::Nul),
+            "jsonl" => Ok(Format::Jsonl),
+            _ => Err(Error::InvalidValue { what: "format", value: s.to_owned() }),
+        }
+    }
+}
+
+pub fn write_matches(out: &mut impl Write, matches: &[Match], config: &SearchConfig, format: Format) -> io::Result<()> {
+    let ansi = config.clone().with_highlight(Highlight::ansi());
+
+    for m in matches {
+        match format {
+            Format::Highlight => writeln!(out, "{}", m.display(config))?,
+            Format::Ansi => writeln!(out, "{}", m.display(&ansi))?,
+            Format::Plain => writeln!(out, "{}", m.line())?,
+            Format::Nul => write!(out, "{}\0", m.line())?,
+            Format::Jsonl => writeln!(out, "{}", json!({
+                "line": m.line(),
+                "positions": m.positions(),
+                "score": m.score(),
+                "id": u64::from(m.id()),
+            }))?,
+        }
+    }
+
+    Ok(())
+}
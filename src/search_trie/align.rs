@@ -0,0 +1,100 @@
+use super::{config::SearchConfig, engine::PathNode, path, score::{bonus, CONSECUTIVE, GAP_EXTENSION, GAP_START}, Symbol};
+
+// how the queried symbols are placed on a matched line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alignment {
+  // the first occurrence of each queried symbol, as found by the trie traversal
+  Greedy,
+  // the placement with the highest score, found once the line is known to match
+  #[default]
+  Optimal
+}
+
+const NONE: i64 = i64::MIN / 2;
+
+// the most cells of the tables `realign` fills for a line, 4 MB of them. past it the line keeps the first
+// occurrences, like fzf falling back from its v2 algorithm to v1, so a long minified line doesn't take two tables
+// of its length times the query length for each keystroke
+const MAX_CELLS: usize = 1 << 18;
+
+// moves the queried symbols of `path` to the best scoring alignment of `query` with the line
+// (Smith-Waterman style, every queried symbol has to be placed in order, the last one in the basename when the
+// path mode requires it).
+// `best[j * n + i]` is the best score of `query[..=j]` with `query[j]` placed on `path[i]`
+pub(super) fn realign<S: Symbol>(path: &mut [PathNode<S>], query: &[S], config: &SearchConfig) {
+  let (n, m) = (path.len(), query.len());
+  if m == 0 || n < m {
+    return;
+  }
+
+  let require_basename = config.path.as_ref().is_some_and(|mode| mode.require_basename);
+  if n * m > MAX_CELLS {
+    if require_basename {
+      path::place_last_in_basename(path, query, config);
+    }
+    return;
+  }
+
+  let basename = path::basename_start(path);
+  let word_start: Vec<bool> = (0..n).map(|i| path[i].val.is_word_start(if i > 0 { Some(&path[i - 1].val) } else { None })).collect();
+  let placeable = |i: usize, j: usize| config.is_placeable(&path[i].val, &query[j], word_start[i]);
+
+  let mut best = vec![NONE; n * m];
+  let mut from = vec![usize::MAX; n * m];
+
+  for i in 0..n {
+    if placeable(i, 0) {
+      best[i] = bonus(path, i, word_start[i], basename, config);
+    }
+  }
+
+  for j in 1..m {
+    // best placement `k` of `query[j - 1]` at least two symbols before `i`, compared by `best + GAP_EXTENSION * k`
+    // since the gap penalty to `i` is `GAP_START + GAP_EXTENSION * (i - k - 2)`
+    let mut gap = (NONE, usize::MAX);
+
+    for i in 1..n {
+      if i >= 2 {
+        let k = i - 2;
+        if best[(j - 1) * n + k] > NONE && best[(j - 1) * n + k] + GAP_EXTENSION * k as i64 > gap.0 {
+          gap = (best[(j - 1) * n + k] + GAP_EXTENSION * k as i64, k);
+        }
+      }
+
+      if !placeable(i, j) {
+        continue;
+      }
+
+      let follow = best[(j - 1) * n + i - 1];
+      let skip = if gap.0 > NONE { gap.0 - GAP_EXTENSION * (i as i64 - 2) - GAP_START } else { NONE };
+      let (prev_score, prev) = if follow > NONE && follow + CONSECUTIVE >= skip {
+        (follow + CONSECUTIVE, i - 1)
+      } else {
+        (skip, gap.1)
+      };
+
+      if prev_score > NONE {
+        best[j * n + i] = prev_score + bonus(path, i, word_start[i], basename, config);
+        from[j * n + i] = prev;
+      }
+    }
+  }
+
+  // the leftmost of the best placements of the last queried symbol, in the basename when it's required. without
+  // one there the line keeps its placement and is dropped for it
+  let last = &best[(m - 1) * n..];
+  let Some(mut i) = (0..n).filter(|&i| last[i] > NONE && (!require_basename || i >= basename)).max_by_key(|&i| (last[i], std::cmp::Reverse(i))) else {
+    return;
+  };
+
+  path.iter_mut().for_each(|p| {
+    p.in_query = false;
+    p.boundary = false;
+  });
+
+  for j in (0..m).rev() {
+    path[i].in_query = true;
+    path[i].boundary = word_start[i];
+    i = from[j * n + i];
+  }
+}
@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+use crate::Error;
+
+use super::{align::Alignment, path::PathMode, Symbol};
+
+// the order `options` lists the lines in, ties are broken lexicographically
+// so the order is the same on every run and for every engine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+  // best score first
+  #[default]
+  Score,
+  Lexicographic,
+  // first inserted first, by `LineId`
+  Insertion,
+  // shortest first
+  Length
+}
+
+impl FromStr for Order {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "score" => Ok(Order::Score),
+      "lexicographic" => Ok(Order::Lexicographic),
+      "insertion" => Ok(Order::Insertion),
+      "length" => Ok(Order::Length),
+      _ => Err(Error::InvalidValue { what: "order", value: s.to_owned() })
+    }
+  }
+}
+
+// how the case of a queried symbol is matched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Case {
+  // a queried symbol only matches itself
+  #[default]
+  Sensitive,
+  // a lowercase queried symbol matches either case, see `Symbol::is_matched_by`
+  Smart
+}
+
+// the markers put around each queried symbol by `Match::display`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Highlight {
+  pub start: String,
+  pub end: String
+}
+
+impl Default for Highlight {
+  fn default() -> Self {
+    Self::new("-", "-")
+  }
+}
+
+impl Highlight {
+  pub fn new(start: impl Into<String>, end: impl Into<String>) -> Self {
+    Self { start: start.into(), end: end.into() }
+  }
+
+  // bold red on a terminal
+  pub fn ansi() -> Self {
+    Self::new("\x1b[1;31m", "\x1b[0m")
+  }
+}
+
+// a cap on the memory of the filtered levels, each keeps its lines for backspace. not a crossover point: filtering
+// beat walking the trie at every number of lines measured on one core, see the README
+const MAX_FILTERED: usize = 1 << 16;
+
+// settings shared by all the engines, applied through `with_config`. built from the default with the `with_`
+// methods, so new settings can be added without breaking the code building one
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct SearchConfig {
+  // treat the lines as file paths, see `PathMode`
+  pub path: Option<PathMode>,
+  // only match the queried symbols at the start of a word (case transitions, `_`, `-`, `.`, `/`, spaces)
+  pub acronym: bool,
+  pub case: Case,
+  // how the queried symbols are placed on the lines reported
+  pub alignment: Alignment,
+  pub order: Order,
+  // the most ranked matches of a query filtered when it gets longer, more are walked again in the trie. a fixed
+  // rule, neither the nodes reached nor the engine's threads change it. the lines a level was filtered to are always
+  // filtered. 0 always walks the trie
+  pub max_filtered: usize,
+  pub highlight: Highlight
+}
+
+impl Default for SearchConfig {
+  fn default() -> Self {
+    Self {
+      path: None,
+      acronym: false,
+      case: Case::default(),
+      alignment: Alignment::default(),
+      order: Order::default(),
+      max_filtered: MAX_FILTERED,
+      highlight: Highlight::default()
+    }
+  }
+}
+
+impl SearchConfig {
+  pub fn with_path(mut self, path: PathMode) -> Self {
+    self.path = Some(path);
+    self
+  }
+
+  pub fn with_acronym(mut self, acronym: bool) -> Self {
+    self.acronym = acronym;
+    self
+  }
+
+  pub fn with_case(mut self, case: Case) -> Self {
+    self.case = case;
+    self
+  }
+
+  pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+    self.alignment = alignment;
+    self
+  }
+
+  pub fn with_order(mut self, order: Order) -> Self {
+    self.order = order;
+    self
+  }
+
+  pub fn with_max_filtered(mut self, max_filtered: usize) -> Self {
+    self.max_filtered = max_filtered;
+    self
+  }
+
+  pub fn with_highlight(mut self, highlight: Highlight) -> Self {
+    self.highlight = highlight;
+    self
+  }
+
+  // whether a queried symbol can be placed on `val`, a symbol of a line which starts a word when `word_start`
+  pub(super) fn is_placeable<S: Symbol>(&self, val: &S, query: &S, word_start: bool) -> bool {
+    let matched = match self.case {
+      Case::Sensitive => val == query,
+      Case::Smart => val.is_matched_by(query)
+    };
+    // in acronym mode a symbol only matches at the start of a word
+    matched && (!self.acronym || word_start)
+  }
+}
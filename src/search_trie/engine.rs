@@ -1,143 +1,417 @@
-use super::TrieNode;
+use std::{ops::Deref, panic::{self, AssertUnwindSafe}, sync::{mpsc::Receiver, Arc}};
 
-pub trait SearchEngine {
-  fn query(&mut self, input: char) -> ();
-  fn options(&self) -> Vec<String>;
+use crate::{Error, Result};
+
+use super::{align::{realign, Alignment}, config::{Highlight, SearchConfig}, history::History, path, score::rank, shared::{sealed::Update, Rebase}, LineId, Snapshot, Symbol, TrieNode};
+
+// the `try_` methods report a failed search (see `Error::Worker`), the others panic on it
+pub trait SearchEngine<S: Symbol = char> {
+  fn try_query(&mut self, input: S) -> Result<()>;
+  // erases the last queried symbol
+  fn try_backspace(&mut self) -> Result<()>;
+  // every line reached by the current query, unranked and so in no particular order
+  fn try_matches(&self) -> Result<Vec<Match<S>>>;
+  // the matches in the order set by the config, ranked once per query and kept
+  // so erasing a symbol or typing it again doesn't rank them again
+  fn try_ranked(&self) -> Result<Arc<Vec<Match<S>>>>;
+  // how many lines `options` lists, without building them
+  fn match_count(&self) -> usize;
+  fn config(&self) -> &SearchConfig;
+
+  fn query(&mut self, input: S) {
+    self.try_query(input).unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  fn backspace(&mut self) {
+    self.try_backspace().unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  fn matches(&self) -> Vec<Match<S>> {
+    self.try_matches().unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  fn ranked(&self) -> Arc<Vec<Match<S>>> {
+    self.try_ranked().unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  // the lines reached by the current query in the order set by the config, with the queried symbols highlighted
+  fn try_options(&self) -> Result<Vec<String>> {
+    let config = self.config();
+    Ok(self.try_ranked()?.iter().map(|m| m.display(config)).collect())
+  }
+
+  fn options(&self) -> Vec<String> {
+    self.try_options().unwrap_or_else(|e| panic!("{}", e))
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct PathNode<S: Symbol = char> {
+  pub(super) val: S,
+  pub(super) in_query: bool,
+  // whether the queried symbol starts a word, always false for symbols which aren't in the query
+  pub(super) boundary: bool
+}
+
+impl<S: Symbol> PathNode<S> {
+  pub fn symbol(&self) -> &S {
+    &self.val
+  }
+
+  pub fn in_query(&self) -> bool {
+    self.in_query
+  }
+
+  // whether the symbol is queried and starts a word
+  pub fn word_start(&self) -> bool {
+    self.boundary
+  }
 }
 
-#[derive(Debug)]
-pub struct PathNode {
-  pub val: char,
-  pub in_query: bool
+// a line reached by the query, `score` is only set once the match is ranked
+#[derive(Debug, Clone)]
+pub struct Match<S: Symbol = char> {
+  pub(super) path: Vec<PathNode<S>>,
+  pub(super) score: i64,
+  pub(super) id: LineId
 }
 
-impl Clone for PathNode {
-    fn clone(&self) -> Self {
-        Self { val: self.val.clone(), in_query: self.in_query.clone() }
+impl<S: Symbol> Match<S> {
+  // the symbols of the line, each marked with whether it is a queried one
+  pub fn path(&self) -> &[PathNode<S>] {
+    &self.path
+  }
+
+  pub fn line(&self) -> String {
+    let mut line = String::new();
+    self.path.iter().for_each(|p| p.val.render(&mut line));
+    line
+  }
+
+  // the indices of the queried symbols among the symbols of the line
+  pub fn positions(&self) -> Vec<usize> {
+    self.path.iter().enumerate().filter(|(_, p)| p.in_query).map(|(i, _)| i).collect()
+  }
+
+  // 0 until the match is ranked, see `SearchEngine::ranked`
+  pub fn score(&self) -> i64 {
+    self.score
+  }
+
+  pub fn id(&self) -> LineId {
+    self.id
+  }
+
+  // the line with the queried symbols between the markers of `config.highlight`, abbreviated in path mode
+  pub fn display(&self, config: &SearchConfig) -> String {
+    match &config.path {
+      Some(mode) if mode.abbreviate => build_path(&path::abbreviate(&self.path), &config.highlight),
+      _ => build_path(&self.path, &config.highlight)
     }
+  }
 }
 
-pub(super) fn traverse<'a>(node: &'a TrieNode, target: char, source: Option<&PathNode>) -> Vec<HistoryNode<'a>> {
-  let mut paths: Vec<HistoryNode<'a>> = Vec::new();
+// the nodes reached by the first occurrence of `target` in each branch below `node`, with the path leading to them.
+// `prev` is the symbol of `node` itself, used to tell whether a match starts a word.
+// the walk keeps its own stack so very long lines don't overflow the (small) stacks of the worker threads
+fn traverse<'a, S: Symbol>(node: &'a TrieNode<S>, target: &S, prev: Option<&S>, config: &SearchConfig) -> Vec<HistoryNode<&'a Arc<TrieNode<S>>, S>> {
+  let mut paths = Vec::new();
+  // the children left to visit at each depth, `path` holds the symbols leading to the deepest ones
+  let mut stack = vec![node.val.iter()];
+  let mut path: Vec<PathNode<S>> = Vec::new();
 
-  for next in &node.val {
-    if next.0 == &target {
-      paths.push(HistoryNode { 
-        node: node.val.get(&target).unwrap(),
-        path: if let Some(s) = source { vec![s.clone(), PathNode { val: *next.0, in_query: true }] } 
-          else { vec![PathNode { val: *next.0, in_query: true }] }
-      });
+  while let Some(children) = stack.last_mut() {
+    let Some((c, next)) = children.next() else {
+      stack.pop();
+      path.pop();
+      continue;
+    };
+
+    let word_start = c.is_word_start(path.last().map_or(prev, |p| Some(&p.val)));
+    if config.is_placeable(c, target, word_start) {
+      let mut p = path.clone();
+      p.push(PathNode { val: c.clone(), in_query: true, boundary: word_start });
+      paths.push(HistoryNode { node: next, path: p });
     } else {
-      let res = traverse(next.1, target, Some(&PathNode { val: *next.0, in_query: false }));
-      
-      for mut r in res {
-        let mut p = if let Some(s) = source {vec![s.clone()]} else { Vec::new() };
-        p.append(&mut r.path);
-        paths.push(HistoryNode { node: r.node, path: p });
-      }
+      path.push(PathNode { val: c.clone(), in_query: false, boundary: false });
+      stack.push(next.val.iter());
     }
   }
 
-  return paths
+  paths
 }
 
-pub(super) fn build_path(path: &Vec<PathNode>) -> String {
-  path.iter().fold(String::new(), |mut acc, PathNode { val, in_query }| {
-    let next_char = match in_query {
-      true => &format!("-{}-", val),
-      false => &format!("{}", val),
-    };
-    acc.push_str(next_char);
+mod sealed {
+  pub trait Sealed {}
+}
+
+impl<S: Symbol> sealed::Sealed for &TrieNode<S> {}
+impl<S: Symbol> sealed::Sealed for Arc<TrieNode<S>> {}
+
+// what the engines hold the nodes they reached with: a borrow of a trie which outlives the engine,
+// or a shared pointer keeping a snapshot of the trie alive for as long as the engine needs it.
+// only implemented by the two, the trie's nodes aren't part of the API
+pub trait NodeRef<S: Symbol>: sealed::Sealed + Deref<Target = TrieNode<S>> + Clone + Send + Sync {
+  // the nodes reached by the first occurrence of `target` in each branch below this one, see `traverse`
+  fn reach(&self, target: &S, prev: Option<&S>, config: &SearchConfig) -> Vec<(Self, Vec<PathNode<S>>)>;
+}
+
+impl<S: Symbol> NodeRef<S> for &TrieNode<S> {
+  fn reach(&self, target: &S, prev: Option<&S>, config: &SearchConfig) -> Vec<(Self, Vec<PathNode<S>>)> {
+    traverse(*self, target, prev, config).into_iter().map(|HistoryNode { node, path }| (&**node, path)).collect()
+  }
+}
+
+impl<S: Symbol> NodeRef<S> for Arc<TrieNode<S>> {
+  fn reach(&self, target: &S, prev: Option<&S>, config: &SearchConfig) -> Vec<(Self, Vec<PathNode<S>>)> {
+    traverse(self, target, prev, config).into_iter().map(|HistoryNode { node, path }| (node.clone(), path)).collect()
+  }
+}
+
+pub(super) fn build_path<S: Symbol>(path: &[PathNode<S>], highlight: &Highlight) -> String {
+  path.iter().fold(String::new(), |mut acc, PathNode { val, in_query, .. }| {
+    if *in_query {
+      acc.push_str(&highlight.start);
+      val.render(&mut acc);
+      acc.push_str(&highlight.end);
+    } else {
+      val.render(&mut acc);
+    }
     acc
   })
 }
 
-pub(super) fn expand(node: &TrieNode) -> Vec<String> {
+// every line below `node`, as the symbols following the path to `node` and the id of the line
+pub(super) fn expand<S: Symbol>(node: &TrieNode<S>) -> Vec<(Vec<S>, LineId)> {
   let mut res = Vec::new();
+  // the children left to visit at each depth, `suffix` holds the symbols leading to the deepest ones
+  let mut stack = vec![node.val.iter()];
+  let mut suffix = Vec::new();
 
-  for (c, node) in &node.val {
-    let path = c.to_string();
-    let expansion = expand(node);
-    
-    if node.end {
-      res.push(path.clone());
-    }
-    
-    if expansion.len() > 0 {
-      expansion.iter().for_each(|v| {
-        res.push(format!("{}{}", path, v));
-      });
+  while let Some(children) = stack.last_mut() {
+    let Some((c, node)) = children.next() else {
+      stack.pop();
+      suffix.pop();
+      continue;
+    };
+
+    suffix.push(c.clone());
+    if let Some(id) = node.id {
+      res.push((suffix.clone(), id));
     }
+    stack.push(node.val.iter());
   }
 
   res
 }
 
-#[derive(Debug)]
-pub(super) struct HistoryNode<'a> {
-  pub node: &'a TrieNode,
-  pub path: Vec<PathNode>
+// the matches for every line below a node reached by the query
+pub(super) fn node_matches<S: Symbol>(node: &TrieNode<S>, path: &[PathNode<S>], query: &[S], config: &SearchConfig) -> Vec<Match<S>> {
+  let mut matches = Vec::new();
+
+  if let Some(id) = node.id {
+    matches.push(Match { path: path.to_vec(), score: 0, id });
+  }
+
+  expand(node).into_iter().for_each(|(suffix, id)| {
+    let mut full = path.to_vec();
+    full.extend(suffix.into_iter().map(|val| PathNode { val, in_query: false, boundary: false }));
+    matches.push(Match { path: full, score: 0, id });
+  });
+
+  matches.iter_mut().for_each(|m| align(&mut m.path, query, config));
+  matches
 }
 
-impl<'a> Clone for HistoryNode<'a> {
-    fn clone(&self) -> Self {
-        Self { node: self.node, path: self.path.clone() }
+// places the queried symbols of a matched line as the config asks. the traversal places each one on its first
+// occurrence, which may leave the last one in a directory when it also occurs in the basename
+fn align<S: Symbol>(path: &mut [PathNode<S>], query: &[S], config: &SearchConfig) {
+  match config.alignment {
+    Alignment::Optimal => realign(path, query, config),
+    Alignment::Greedy if config.path.as_ref().is_some_and(|mode| mode.require_basename) => {
+      path::place_last_in_basename(path, query, config);
     }
+    Alignment::Greedy => ()
+  }
 }
 
-pub struct Engine<'a> {
-  // store the different current Trie_nodes reached and PathNodes to build the line with history for backspace
-  history: Vec<Vec<HistoryNode<'a>>>,
+// the symbols of `line` with the first occurrence of each queried symbol marked, as the traversal of the trie finds them
+fn place<'a, S: Symbol + 'a>(line: impl Iterator<Item = &'a S>, query: &[S], config: &SearchConfig) -> Option<Vec<PathNode<S>>> {
+  let mut path: Vec<PathNode<S>> = Vec::new();
+  let mut rest = query.iter().peekable();
+
+  for c in line {
+    let word_start = c.is_word_start(path.last().map(|p| &p.val));
+    let in_query = rest.peek().is_some_and(|q| config.is_placeable(c, q, word_start));
+    if in_query {
+      rest.next();
+    }
+    path.push(PathNode { val: c.clone(), in_query, boundary: in_query && word_start });
+  }
+
+  rest.peek().is_none().then_some(path)
 }
 
-impl<'a> Engine<'a> {
-  pub fn new(root: &'a TrieNode) -> Self {
-    Self { 
-      history:  vec![vec![HistoryNode { node: root, path: Vec::new() }]],
+// the matches among `lines` which the query still reaches, the same ones walking the trie would give. like the
+// nodes reached by a walk they are only aligned once the matches are asked for, see `align_lines`, but the last
+// queried symbol is already moved to the basename when it's required, to count them
+pub(super) fn filter_lines<S: Symbol>(lines: &[Match<S>], query: &[S], config: &SearchConfig) -> Vec<Match<S>> {
+  let require_basename = config.path.as_ref().is_some_and(|mode| mode.require_basename);
+  lines.iter().filter_map(|m| {
+    let mut path = place(m.path.iter().map(|p| &p.val), query, config)?;
+    if require_basename {
+      path::place_last_in_basename(&mut path, query, config);
     }
-  }  
+    Some(Match { path, score: 0, id: m.id })
+  }).collect()
+}
+
+// the matches of a filtered level, with the queried symbols placed as the config asks
+pub(super) fn align_lines<S: Symbol>(lines: &[Match<S>], query: &[S], config: &SearchConfig) -> Vec<Match<S>> {
+  lines.iter().map(|m| {
+    let mut m = m.clone();
+    align(&mut m.path, query, config);
+    m
+  }).collect()
+}
+
+// the number of lines reached by the query. the traversal stops at the first match in each branch so the nodes
+// never share a branch and their `ref_count`s add up, only `require_basename` has to look at the lines. the lines
+// added and removed since the snapshot of the nodes are counted on their own
+pub(super) fn count_matches<N: NodeRef<S>, S: Symbol>(history: &History<N, S>, config: &SearchConfig) -> usize {
+  let require_basename = config.path.as_ref().is_some_and(|mode| mode.require_basename);
+  let count = |lines: &[Match<S>]| if require_basename { lines.iter().filter(|m| path::last_in_basename(&m.path)).count() } else { lines.len() };
+
+  if let Some(lines) = history.lines() {
+    return count(lines);
+  }
+
+  let walked: usize = if require_basename {
+    history.nodes().iter().map(|HistoryNode { node, path }| count(&node_matches(node, path, history.query(), config))).sum()
+  } else {
+    history.nodes().iter().map(|HistoryNode { node, .. }| node.ref_count as usize).sum()
+  };
+  let (added, removed) = history.delta();
+  walked + count(added) - count(removed)
+}
+
+// runs a worker's part of the search, a panic (e.g. in a `Symbol` implementation) is caught and given back
+// as an error instead of unwinding through the scope, which would panic the engine's thread and, for a
+// `TPEngine`, take the pool's thread down with it. the engine's own thread runs the filtering and aligning of the
+// lines through it too, so a `try_` call fails the same way whichever path the search took
+pub(super) fn work<T>(job: impl FnOnce() -> T) -> Result<T> {
+  panic::catch_unwind(AssertUnwindSafe(job)).map_err(|payload| {
+    let reason = match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+      (Some(reason), _) => reason.to_string(),
+      (_, Some(reason)) => reason.clone(),
+      _ => "a worker panicked".to_owned()
+    };
+    Error::Worker(reason)
+  })
+}
+
+// the results sent by `jobs` workers, one each, or the first failure among them. the senders must all be
+// held by the workers, or a worker which stopped without sending leaves this waiting forever
+pub(super) fn gather<T>(results: Receiver<Result<Vec<T>>>, jobs: usize) -> Result<Vec<T>> {
+  let mut res = Vec::new();
+  for _ in 0..jobs {
+    res.append(&mut results.recv().map_err(|_| Error::Worker("a worker stopped without its results".to_owned()))??);
+  }
+  Ok(res)
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct HistoryNode<N, S: Symbol> {
+  pub node: N,
+  pub path: Vec<PathNode<S>>
+}
+
+impl<N: NodeRef<S>, S: Symbol> HistoryNode<N, S> {
+  // the nodes reached from this one by the next queried symbol, with the whole path leading to them
+  pub fn next(&self, target: &S, config: &SearchConfig) -> Vec<Self> {
+    self.node.reach(target, self.path.last().map(|p| &p.val), config).into_iter().map(|(node, suffix)| {
+      let mut path = self.path.clone();
+      path.extend(suffix);
+      HistoryNode { node, path }
+    }).collect()
+  }
+}
+
+pub struct Engine<N, S: Symbol = char> {
+  // the nodes reached after each queried symbol, kept for backspace
+  history: History<N, S>,
+  config: SearchConfig
 }
 
-impl<'a> SearchEngine for Engine<'a> {
-  fn query(&mut self, input: char) {
-    if input.eq(&'*') {
-      if self.history.len() > 1 {
-        self.history.pop();
-      }
-      return;
+impl<N: NodeRef<S>, S: Symbol> Engine<N, S> {
+  pub fn new(root: N) -> Self {
+    Self {
+      history: History::new(root),
+      config: SearchConfig::default()
     }
+  }
 
-    let curr = self.history.last().unwrap().to_vec();
-    let mut next: Vec<HistoryNode> = Vec::new();
+  pub fn with_config(mut self, config: SearchConfig) -> Self {
+    self.config = config;
+    self.history.reset();
+    self
+  }
+}
 
-    for HistoryNode {node, path } in curr {
-      next.append(&mut traverse(node, input, None).iter_mut().map(|hp| {
-        let mut t_path = path.to_vec();
-        t_path.append(&mut hp.path);
-        HistoryNode {node: hp.node, path: t_path }
-      }).collect());
+impl<N: NodeRef<S>, S: Symbol> SearchEngine<S> for Engine<N, S> {
+  fn try_query(&mut self, input: S) -> Result<()> {
+    if self.history.restore(&input) || self.history.filter(&input, &self.config) {
+      return Ok(());
     }
-    
-    self.history.push(next);
+
+    let next = self.history.nodes().iter().flat_map(|node| node.next(&input, &self.config)).collect();
+    self.history.push(input, next, &self.config);
+    Ok(())
   }
 
-  fn options(&self) -> Vec<String> {
-    let mut options_list = Vec::new();
-    if let Some(nodes) = self.history.last() {
-      for HistoryNode { node, path } in nodes {
-        let path1 = build_path(path);
-        let path2_list = expand(node);
-        
-        if node.end {
-          options_list.push(path1.clone());
-        }
-
-        path2_list.iter().for_each(|path2| {
-          options_list.push(format!("{}{}", path1, path2));
-        });
-      }
+  fn try_backspace(&mut self) -> Result<()> {
+    self.history.pop();
+    Ok(())
+  }
+
+  fn try_matches(&self) -> Result<Vec<Match<S>>> {
+    if let Some(lines) = self.history.lines() {
+      return Ok(align_lines(lines, self.history.query(), &self.config));
     }
 
-    options_list
+    let mut matches = Vec::new();
+    for HistoryNode { node, path } in self.history.nodes() {
+      matches.append(&mut node_matches(node, path, self.history.query(), &self.config));
+    }
+
+    Ok(self.history.patch(matches, &self.config))
+  }
+
+  fn try_ranked(&self) -> Result<Arc<Vec<Match<S>>>> {
+    self.history.ranked(|| Ok(rank(self.try_matches()?, &self.config)))
   }
-}
\ No newline at end of file
+
+  fn match_count(&self) -> usize {
+    count_matches(&self.history, &self.config)
+  }
+
+  fn config(&self) -> &SearchConfig {
+    &self.config
+  }
+}
+
+impl<S: Symbol> Update<S> for Engine<Snapshot<S>, S> {
+  fn update(&mut self, added: &[(Vec<S>, LineId)], removed: &[(Vec<S>, LineId)]) {
+    self.history.update(added, removed, &self.config);
+  }
+}
+
+impl<S: Symbol> Rebase<S> for Engine<Snapshot<S>, S> {
+  fn rebase(&mut self, root: Snapshot<S>) {
+    self.history = History::new(root);
+  }
+
+  fn with_config(self, config: SearchConfig) -> Self {
+    Engine::with_config(self, config)
+  }
+}
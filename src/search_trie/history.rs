@@ -0,0 +1,221 @@
+use std::{collections::{HashSet, VecDeque}, sync::{Arc, OnceLock}};
+
+use crate::Result;
+
+use super::{config::SearchConfig, engine::{align_lines, filter_lines, HistoryNode, Match, NodeRef, PathNode}, LineId, Symbol};
+
+// how many levels erased by backspace are kept, to be brought back when the same symbols are typed again
+const RECENT: usize = 64;
+
+struct Level<N, S: Symbol> {
+  // the nodes reached by the query, empty when the level was filtered from the lines of the one before
+  nodes: Vec<HistoryNode<N, S>>,
+  // the unranked matches of a level filtered from the one before instead of walked in the trie
+  lines: Option<Arc<Vec<Match<S>>>>,
+  // the ranked matches of the level, computed the first time they are asked for
+  ranked: OnceLock<Arc<Vec<Match<S>>>>,
+  // the lines reaching a walked level which were added to the trie since the snapshot of its nodes, and the ones
+  // below its nodes which were removed since, see `History::update`
+  added: Vec<Match<S>>,
+  removed: Vec<Match<S>>
+}
+
+impl<N, S: Symbol> Level<N, S> {
+  fn new(nodes: Vec<HistoryNode<N, S>>) -> Self {
+    Self { nodes, lines: None, ranked: OnceLock::new(), added: Vec::new(), removed: Vec::new() }
+  }
+
+  fn filtered(lines: Vec<Match<S>>) -> Self {
+    Self { nodes: Vec::new(), lines: Some(Arc::new(lines)), ranked: OnceLock::new(), added: Vec::new(), removed: Vec::new() }
+  }
+
+  // takes in the lines added to and removed from the trie, `query` reaching the level. the ranked matches are only
+  // dropped when a changed line reaches it
+  fn update(&mut self, query: &[S], added: &[Match<S>], removed: &[Match<S>], config: &SearchConfig) {
+    let added = filter_lines(added, query, config);
+    let removed = filter_lines(removed, query, config);
+    if added.is_empty() && removed.is_empty() {
+      return;
+    }
+    self.ranked = OnceLock::new();
+
+    let gone: HashSet<LineId> = removed.iter().map(|m| m.id).collect();
+    match &mut self.lines {
+      Some(lines) => {
+        let mut updated: Vec<Match<S>> = lines.iter().filter(|m| !gone.contains(&m.id)).cloned().collect();
+        updated.extend(added);
+        *lines = Arc::new(updated);
+      }
+      None => {
+        // a line added since is below none of the nodes, it's only taken out of the added ones
+        for m in removed {
+          match self.added.iter().position(|a| a.id == m.id) {
+            Some(i) => {
+              self.added.swap_remove(i);
+            }
+            None => self.removed.push(m)
+          }
+        }
+        self.added.extend(added);
+      }
+    }
+  }
+}
+
+// the nodes reached after each symbol of the query, shared by the engines.
+// an engine borrows the trie or holds a snapshot of it, so the cached matches stay valid for the life of the engine
+pub(super) struct History<N, S: Symbol> {
+  // the first level is the root
+  levels: Vec<Level<N, S>>,
+  query: Vec<S>,
+  // the levels erased by backspace with the query reaching them, most recent last
+  recent: VecDeque<(Vec<S>, Level<N, S>)>
+}
+
+impl<N: NodeRef<S>, S: Symbol> History<N, S> {
+  pub fn new(root: N) -> Self {
+    Self {
+      levels: vec![Level::new(vec![HistoryNode { node: root, path: Vec::new() }])],
+      query: Vec::new(),
+      recent: VecDeque::new()
+    }
+  }
+
+  // the nodes reached by the current query
+  pub fn nodes(&self) -> &[HistoryNode<N, S>] {
+    &self.levels.last().unwrap().nodes
+  }
+
+  pub fn query(&self) -> &[S] {
+    &self.query
+  }
+
+  // the matches of the current query when they were filtered from the previous ones, the nodes are empty then
+  pub fn lines(&self) -> Option<&Arc<Vec<Match<S>>>> {
+    self.levels.last().unwrap().lines.as_ref()
+  }
+
+  // brings back the level reached by typing `input`, if it was erased recently
+  pub fn restore(&mut self, input: &S) -> bool {
+    self.query.push(input.clone());
+
+    match self.recent.iter().rposition(|(query, _)| *query == self.query) {
+      Some(i) => {
+        let (_, level) = self.recent.remove(i).unwrap();
+        self.levels.push(level);
+        true
+      }
+      None => {
+        self.query.pop();
+        false
+      }
+    }
+  }
+
+  // reaches the next level by filtering the lines of the current one instead of walking the trie, when they are
+  // already built: the lines the level was filtered to, or its ranked matches when there are at most
+  // `config.max_filtered` of them. scanning them beats walking again, which has to build the lines below the nodes
+  // it reaches once the matches are asked for. building them from the nodes the level reached to filter them costs
+  // about twice the walk though, even once the nodes hold a single line each, so a level walked and never ranked is
+  // walked from. see the README for the measurements. once a level is filtered the ones after it are too, the
+  // matches only get fewer
+  pub fn filter(&mut self, input: &S, config: &SearchConfig) -> bool {
+    let level = self.levels.last().unwrap();
+    let source = match (&level.lines, level.ranked.get()) {
+      // a filtered level has no nodes to walk from
+      (Some(lines), _) => lines.clone(),
+      // the ranked matches leave out the lines without a match in their basename, which a longer query could have
+      (None, Some(ranked)) if ranked.len() <= config.max_filtered && !config.path.as_ref().is_some_and(|p| p.require_basename) => ranked.clone(),
+      _ => return false
+    };
+
+    // nothing changes until the lines are filtered, which may panic in a `Symbol` implementation
+    let query = [&self.query[..], std::slice::from_ref(input)].concat();
+    let lines = filter_lines(&source, &query, config);
+    self.query = query;
+    self.levels.push(Level::filtered(lines));
+    true
+  }
+
+  // the next level, walked from the nodes of the current one. the lines added and removed since the snapshot of
+  // the nodes are filtered to the ones the longer query still reaches
+  pub fn push(&mut self, input: S, nodes: Vec<HistoryNode<N, S>>, config: &SearchConfig) {
+    let query = [&self.query[..], &[input]].concat();
+    let current = self.levels.last().unwrap();
+    let mut level = Level::new(nodes);
+    level.added = filter_lines(&current.added, &query, config);
+    level.removed = filter_lines(&current.removed, &query, config);
+    self.query = query;
+    self.levels.push(level);
+  }
+
+  // the lines added to and removed from the trie since the snapshot of the nodes of the current level,
+  // which reach it
+  pub fn delta(&self) -> (&[Match<S>], &[Match<S>]) {
+    let level = self.levels.last().unwrap();
+    (&level.added, &level.removed)
+  }
+
+  // the matches walked from the nodes of the current level without the lines removed since, with the ones added
+  pub fn patch(&self, mut matches: Vec<Match<S>>, config: &SearchConfig) -> Vec<Match<S>> {
+    let (added, removed) = self.delta();
+    if !removed.is_empty() {
+      let gone: HashSet<LineId> = removed.iter().map(|m| m.id).collect();
+      matches.retain(|m| !gone.contains(&m.id));
+    }
+    matches.extend(align_lines(added, &self.query, config));
+    matches
+  }
+
+  // keeps every level, the ones erased by backspace too, up to date with the lines added to and removed from the
+  // trie since the snapshot of its nodes, which the engine keeps searching. walking the trie again would throw
+  // away what the levels found and cached
+  pub fn update(&mut self, added: &[(Vec<S>, LineId)], removed: &[(Vec<S>, LineId)], config: &SearchConfig) {
+    let lines = |changed: &[(Vec<S>, LineId)]| -> Vec<Match<S>> {
+      changed.iter().map(|(line, id)| Match {
+        path: line.iter().map(|val| PathNode { val: val.clone(), in_query: false, boundary: false }).collect(),
+        score: 0,
+        id: *id
+      }).collect()
+    };
+    let (added, removed) = (lines(added), lines(removed));
+
+    for (i, level) in self.levels.iter_mut().enumerate() {
+      level.update(&self.query[..i], &added, &removed, config);
+    }
+    for (query, level) in self.recent.iter_mut() {
+      level.update(query, &added, &removed, config);
+    }
+  }
+
+  pub fn pop(&mut self) {
+    if self.levels.len() > 1 {
+      let level = self.levels.pop().unwrap();
+      self.recent.push_back((self.query.clone(), level));
+      self.query.pop();
+
+      if self.recent.len() > RECENT {
+        self.recent.pop_front();
+      }
+    }
+  }
+
+  // the ranked matches of the current query, `rank` only runs the first time they are asked for (or until it succeeds)
+  pub fn ranked(&self, rank: impl FnOnce() -> Result<Vec<Match<S>>>) -> Result<Arc<Vec<Match<S>>>> {
+    let cached = &self.levels.last().unwrap().ranked;
+    if let Some(ranked) = cached.get() {
+      return Ok(ranked.clone());
+    }
+
+    let ranked = Arc::new(rank()?);
+    Ok(cached.get_or_init(|| ranked).clone())
+  }
+
+  // back to the empty query, dropping everything cached
+  pub fn reset(&mut self) {
+    self.levels.truncate(1);
+    self.levels[0].ranked = OnceLock::new();
+    self.query.clear();
+    self.recent.clear();
+  }
+}
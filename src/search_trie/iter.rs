@@ -0,0 +1,57 @@
+use super::{Symbol, TrieNode};
+
+// the lines below a node in lexicographic order, walked with an explicit stack instead of collecting them first
+pub struct Lines<'a, S: Symbol> {
+  // the children left to visit at each depth, sorted in reverse so the smallest one is popped first
+  stack: Vec<Vec<(&'a S, &'a TrieNode<S>)>>,
+  path: Vec<S>,
+  // the node the walk starts from, its own line comes before the ones below it
+  start: Option<&'a TrieNode<S>>
+}
+
+impl<'a, S: Symbol> Lines<'a, S> {
+  pub(super) fn new(start: Option<&'a TrieNode<S>>, prefix: Vec<S>) -> Self {
+    Self { stack: Vec::new(), path: prefix, start }
+  }
+
+  fn push_children(&mut self, node: &'a TrieNode<S>) {
+    let mut children: Vec<(&S, &TrieNode<S>)> = node.val.iter().map(|(c, node)| (c, &**node)).collect();
+    children.sort_unstable_by(|a, b| b.0.cmp(a.0));
+    self.stack.push(children);
+  }
+
+  fn render(&self) -> String {
+    let mut line = String::new();
+    self.path.iter().for_each(|s| s.render(&mut line));
+    line
+  }
+}
+
+impl<S: Symbol> Iterator for Lines<'_, S> {
+  type Item = String;
+
+  fn next(&mut self) -> Option<String> {
+    if let Some(start) = self.start.take() {
+      self.push_children(start);
+      if start.id.is_some() {
+        return Some(self.render());
+      }
+    }
+
+    loop {
+      match self.stack.last_mut()?.pop() {
+        Some((c, node)) => {
+          self.path.push(c.clone());
+          self.push_children(node);
+          if node.id.is_some() {
+            return Some(self.render());
+          }
+        }
+        None => {
+          self.stack.pop();
+          self.path.pop();
+        }
+      }
+    }
+  }
+}
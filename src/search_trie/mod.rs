@@ -1,99 +1,335 @@
-use std::{collections::HashMap, time::Instant};
+use std::{collections::{BTreeMap, HashMap}, fmt, io::BufRead, sync::Arc};
+
+use crate::Result;
 
 mod engine;
 mod t_engine;
 mod tp_engine;
+mod symbol;
+mod config;
+mod path;
+mod score;
+mod align;
+mod iter;
+mod history;
+mod shared;
+mod stats;
 
-use engine::Engine;
-use t_engine::TEngine;
-use tp_engine::TPEngine;
-pub use engine::SearchEngine;
+pub use engine::{Engine, Match, NodeRef, PathNode, SearchEngine};
+pub use t_engine::TEngine;
+pub use tp_engine::TPEngine;
+pub use symbol::{Grapheme, Symbol, Token};
+pub use config::{Case, Highlight, Order, SearchConfig};
+pub use path::PathMode;
+pub use align::Alignment;
+pub use iter::Lines;
+pub use score::score_positions;
+pub use shared::{LiveEngine, Rebase, SharedTrie};
+pub use stats::TrieStats;
 
-#[derive(Debug)]
-pub struct TrieNode {
+// a handle on the lines of a trie as they were when it was taken, see `SearchTrie::snapshot`
+pub type Snapshot<S = char> = Arc<TrieNode<S>>;
+
+// engines which own a snapshot of the trie instead of borrowing it, so they are `Send + 'static` and
+// can be kept in long-lived structs or moved to other threads. the borrowing ones skip the reference counting
+pub type OwnedEngine<S = char> = Engine<Snapshot<S>, S>;
+pub type OwnedTEngine<S = char> = TEngine<Snapshot<S>, S>;
+pub type OwnedTPEngine<S = char> = TPEngine<Snapshot<S>, S>;
+
+// the identity of a line in a trie, given out in insertion order and never reused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineId(u64);
+
+impl From<LineId> for u64 {
+  fn from(id: LineId) -> Self {
+    id.0
+  }
+}
+
+impl fmt::Display for LineId {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+// the children are shared so a snapshot of the trie is a clone of the root, the nodes changed after a snapshot
+// is taken are copied on write (only along the path of the changed line)
+#[derive(Debug, Clone)]
+pub struct TrieNode<S: Symbol = char> {
   ref_count: u32,
-  pub val: HashMap<char, Self>,
-  pub end: bool
+  val: HashMap<S, Arc<Self>>,
+  // set when a line ends at this node
+  id: Option<LineId>
 }
 
-impl TrieNode {
+impl<S: Symbol> TrieNode<S> {
   pub fn new() -> Self {
     Self {
       ref_count: 0,
       val: HashMap::new(),
-      end: false
+      id: None
     }
   }
 }
 
-#[derive(Debug)]
-pub struct SearchTrie {
-  root: TrieNode
+// takes the branches apart one node at a time, the default drop recurses once per symbol
+// and overflows the stack on very long lines. the branches still shared with a snapshot are left to it
+impl<S: Symbol> Drop for TrieNode<S> {
+  fn drop(&mut self) {
+    let mut stack: Vec<Arc<TrieNode<S>>> = self.val.drain().map(|(_, node)| node).collect();
+    while let Some(node) = stack.pop() {
+      if let Some(mut node) = Arc::into_inner(node) {
+        stack.extend(node.val.drain().map(|(_, node)| node));
+      }
+    }
+  }
 }
 
-fn list_solver(curr: &TrieNode, path: &mut String, res: &mut Vec<String>) {
-  if curr.val.is_empty() {
-    return res.push(path.to_owned());
+impl<S: Symbol> Default for TrieNode<S> {
+  fn default() -> Self {
+    Self::new()
   }
+}
 
-  for (c, next) in &curr.val {
-    path.push_str(&c.to_string());
-    list_solver(&next, path, res);
-    path.pop();
+// calls `line` with the symbols of each line read from `reader`, without its line break (`\n` or `\r\n`).
+// the lines are read as bytes and split by `Symbol::split_bytes`, so a stray binary line doesn't stop the rest
+pub(super) fn read_lines<S: Symbol>(mut reader: impl BufRead, mut line: impl FnMut(Vec<S>)) -> Result<usize> {
+  let mut buf = Vec::new();
+  let mut count = 0;
+
+  while reader.read_until(b'\n', &mut buf)? > 0 {
+    let end = buf.strip_suffix(b"\n").unwrap_or(&buf);
+    line(S::split_bytes(end.strip_suffix(b"\r").unwrap_or(end)));
+    count += 1;
+    buf.clear();
   }
+
+  Ok(count)
+}
+
+#[derive(Debug)]
+pub struct SearchTrie<S: Symbol = char> {
+  root: Arc<TrieNode<S>>,
+  // the symbols of each line, to find it from its id
+  lines: BTreeMap<LineId, Vec<S>>,
+  next_id: u64,
+  // bumped by every insert or remove which changes the trie
+  version: u64
 }
 
-impl SearchTrie {
+impl<S: Symbol> SearchTrie<S> {
   pub fn new() -> Self {
-    Self { root: TrieNode::new() }
+    Self { root: Arc::default(), lines: BTreeMap::new(), next_id: 0, version: 0 }
   }
 
+  // the lines as they are now, unaffected by later inserts and removes. only copies the root pointer,
+  // the nodes are copied when the trie changes them while a snapshot still holds them
+  pub fn snapshot(&self) -> Snapshot<S> {
+    self.root.clone()
+  }
+
+  // changes whenever the trie does, e.g. to tell whether a snapshot is out of date
+  pub fn version(&self) -> u64 {
+    self.version
+  }
+
+  // every line in lexicographic order
   pub fn list(&self) -> Vec<String> {
-    let timer = Instant::now();
-    let mut res: Vec<String> = Vec::new();
+    self.iter().collect()
+  }
 
-    list_solver(&self.root,&mut String::new(), &mut res);
+  // every line in lexicographic order, without collecting them first
+  pub fn iter(&self) -> Lines<'_, S> {
+    Lines::new(Some(&self.root), Vec::new())
+  }
 
-    println!("time elapsed: {}", timer.elapsed().as_millis());
-    return res;
+  // the lines starting with `prefix` in lexicographic order
+  pub fn iter_prefix(&self, prefix: &str) -> Lines<'_, S> {
+    let prefix = S::split(prefix);
+    Lines::new(self.find(&prefix), prefix)
   }
 
-  pub fn insert(&mut self, line: &str) {
-    let mut pointer = &mut self.root;
-    for c in line.chars() {
-      if !pointer.val.contains_key(&c) {
-        pointer.val.insert(c, TrieNode::new());
-      }
-      
-      pointer = pointer.val.get_mut(&c).unwrap();
+  // the number of lines in the trie
+  pub fn len(&self) -> usize {
+    self.root.ref_count as usize
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  fn find(&self, line: &[S]) -> Option<&TrieNode<S>> {
+    let mut pointer = &self.root;
+    for c in line {
+      pointer = pointer.val.get(c)?;
+    }
+    Some(pointer)
+  }
+
+  pub fn contains(&self, line: &str) -> bool {
+    self.find(&S::split(line)).is_some_and(|node| node.id.is_some())
+  }
+
+  pub fn starts_with(&self, prefix: &str) -> bool {
+    self.count_prefix(prefix) > 0
+  }
+
+  // the number of lines starting with `prefix`
+  pub fn count_prefix(&self, prefix: &str) -> usize {
+    self.find(&S::split(prefix)).map_or(0, |node| node.ref_count as usize)
+  }
+
+  // `prefix` extended for as long as all the lines starting with it agree, like a shell's Tab completion.
+  // none when no line starts with `prefix`
+  pub fn complete(&self, prefix: &str) -> Option<String> {
+    let mut completion = prefix.to_owned();
+    let mut pointer = self.find(&S::split(prefix)).filter(|node| node.ref_count > 0)?;
+
+    while pointer.id.is_none() && pointer.val.len() == 1 {
+      let (c, next) = pointer.val.iter().next().unwrap();
+      c.render(&mut completion);
+      pointer = next;
+    }
+
+    Some(completion)
+  }
+
+  // the longest prefix shared by every line
+  pub fn longest_common_prefix(&self) -> String {
+    self.complete("").unwrap_or_default()
+  }
+
+  pub fn insert(&mut self, line: &str) -> LineId {
+    self.insert_symbols(S::split(line))
+  }
+
+  // inserts every line read from `reader`, without the line breaks. the bytes which aren't valid UTF-8 are kept by
+  // `u8` symbols and replaced by the others, see `Symbol::split_bytes`. the number of lines read
+  pub fn read_lines(&mut self, reader: impl BufRead) -> Result<usize> {
+    read_lines(reader, |line| {
+      self.insert_symbols(line);
+    })
+  }
+
+  // inserts an already split line, e.g. the raw bytes of a filename which isn't valid UTF-8.
+  // every node counts the lines going through it, so inserting a line twice doesn't change the trie
+  // and gives back the id of the first insert
+  pub fn insert_symbols(&mut self, line: impl IntoIterator<Item = S>) -> LineId {
+    let line: Vec<S> = line.into_iter().collect();
+    if let Some(id) = self.find(&line).and_then(|node| node.id) {
+      return id;
+    }
+
+    let id = LineId(self.next_id);
+    self.next_id += 1;
+    self.version += 1;
+
+    let mut pointer = Arc::make_mut(&mut self.root);
+    pointer.ref_count += 1;
+    for c in &line {
+      pointer = Arc::make_mut(pointer.val.entry(c.clone()).or_default());
       pointer.ref_count += 1;
     }
-    pointer.end = true;
-  }
-
-  pub fn remove(&mut self, line: &str) {
-    let mut pointer = &mut self.root;
-    for c in line.chars() {
-      if let Some(node) = pointer.val.get_mut(&c) {
-        if node.ref_count == 1 {
-          pointer.val.remove(&c);
-        } else {
-          pointer = pointer.val.get_mut(&c).unwrap();
-          pointer.ref_count -= 1;
-        }
+    pointer.id = Some(id);
+
+    self.lines.insert(id, line);
+    id
+  }
+
+  // the line with the given id, if it is still in the trie
+  pub fn get(&self, id: LineId) -> Option<String> {
+    self.lines.get(&id).map(|line| {
+      let mut res = String::new();
+      line.iter().for_each(|s| s.render(&mut res));
+      res
+    })
+  }
+
+  pub fn get_symbols(&self, id: LineId) -> Option<&[S]> {
+    self.lines.get(&id).map(Vec::as_slice)
+  }
+
+  pub fn remove(&mut self, line: &str) -> Option<LineId> {
+    self.remove_symbols(S::split(line))
+  }
+
+  // removes the line and gives back its id, if it was in the trie
+  pub fn remove_symbols(&mut self, line: impl IntoIterator<Item = S>) -> Option<LineId> {
+    let line: Vec<S> = line.into_iter().collect();
+    let id = self.find(&line)?.id?;
+
+    self.lines.remove(&id);
+    self.version += 1;
+    let mut pointer = Arc::make_mut(&mut self.root);
+    pointer.ref_count -= 1;
+    for c in line {
+      // the rest of the line is only used by this line
+      if pointer.val[&c].ref_count == 1 {
+        pointer.val.remove(&c);
+        return Some(id);
       }
+
+      pointer = Arc::make_mut(pointer.val.get_mut(&c).unwrap());
+      pointer.ref_count -= 1;
     }
+    pointer.id = None;
+
+    Some(id)
+  }
+
+  // removes the line with the given id and gives it back, if it was in the trie
+  pub fn remove_by_id(&mut self, id: LineId) -> Option<String> {
+    let line = self.get(id)?;
+    let symbols = self.lines[&id].clone();
+    self.remove_symbols(symbols);
+    Some(line)
   }
 
-  pub fn engine(&self) -> Engine {
+  pub fn engine(&self) -> Engine<&TrieNode<S>, S> {
     Engine::new(&self.root)
   }
 
-  pub fn t_engine(&self) -> TEngine {
+  pub fn t_engine(&self) -> TEngine<&TrieNode<S>, S> {
     TEngine::new(&self.root)
   }
 
-  pub fn tp_engine(&self, thread_count: usize) -> TPEngine {
+  pub fn tp_engine(&self, thread_count: usize) -> TPEngine<&TrieNode<S>, S> {
     TPEngine::new(&self.root, thread_count)
   }
+
+  // the engines on a snapshot of the trie, they keep searching the lines as they are now
+  // whatever happens to the trie (even once it is dropped)
+  pub fn owned_engine(&self) -> OwnedEngine<S> {
+    Engine::new(self.snapshot())
+  }
+
+  pub fn owned_t_engine(&self) -> OwnedTEngine<S> {
+    TEngine::new(self.snapshot())
+  }
+
+  pub fn owned_tp_engine(&self, thread_count: usize) -> OwnedTPEngine<S> {
+    TPEngine::new(self.snapshot(), thread_count)
+  }
+}
+
+impl<S: Symbol, L: AsRef<str>> Extend<L> for SearchTrie<S> {
+  fn extend<T: IntoIterator<Item = L>>(&mut self, lines: T) {
+    lines.into_iter().for_each(|line| {
+      self.insert(line.as_ref());
+    });
+  }
+}
+
+impl<S: Symbol, L: AsRef<str>> FromIterator<L> for SearchTrie<S> {
+  fn from_iter<T: IntoIterator<Item = L>>(lines: T) -> Self {
+    let mut trie = Self::new();
+    trie.extend(lines);
+    trie
+  }
+}
+
+impl<S: Symbol> Default for SearchTrie<S> {
+  fn default() -> Self {
+    Self::new()
+  }
 }
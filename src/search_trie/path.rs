@@ -0,0 +1,114 @@
+use super::{config::SearchConfig, engine::PathNode, Symbol};
+
+// matching settings for lines which are file paths
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct PathMode {
+  // only keep lines where the last queried symbol is in the basename
+  pub require_basename: bool,
+  // shorten the directories which hold no queried symbol to their first symbol
+  pub abbreviate: bool
+}
+
+impl Default for PathMode {
+  fn default() -> Self {
+    Self { require_basename: false, abbreviate: true }
+  }
+}
+
+impl PathMode {
+  pub fn with_require_basename(mut self, require_basename: bool) -> Self {
+    self.require_basename = require_basename;
+    self
+  }
+
+  pub fn with_abbreviate(mut self, abbreviate: bool) -> Self {
+    self.abbreviate = abbreviate;
+    self
+  }
+}
+
+// index of the first symbol of the basename, a trailing separator is not treated as the start of the basename
+pub(super) fn basename_start<S: Symbol>(path: &[PathNode<S>]) -> usize {
+  let end = match path.last() {
+    Some(last) if last.val.is_path_separator() => path.len() - 1,
+    _ => path.len()
+  };
+
+  path[..end].iter().rposition(|p| p.val.is_path_separator()).map_or(0, |i| i + 1)
+}
+
+pub(super) fn is_segment_start<S: Symbol>(path: &[PathNode<S>], i: usize) -> bool {
+  i == 0 || path[i - 1].val.is_path_separator()
+}
+
+pub(super) fn last_in_basename<S: Symbol>(path: &[PathNode<S>]) -> bool {
+  match path.iter().rposition(|p| p.in_query) {
+    Some(i) => i >= basename_start(path),
+    None => true
+  }
+}
+
+// moves the last queried symbol into the basename when the line allows it, after the first occurrences of the
+// others, and tells whether it is there. a line with the last queried symbol in a directory may still have
+// another occurrence of it in the basename
+pub(super) fn place_last_in_basename<S: Symbol>(path: &mut [PathNode<S>], query: &[S], config: &SearchConfig) -> bool {
+  let Some((last, rest)) = query.split_last() else {
+    return true;
+  };
+  if last_in_basename(path) {
+    return true;
+  }
+
+  let n = path.len();
+  let word_start: Vec<bool> = (0..n).map(|i| path[i].val.is_word_start(if i > 0 { Some(&path[i - 1].val) } else { None })).collect();
+  let placeable = |i: usize, q: &S| config.is_placeable(&path[i].val, q, word_start[i]);
+
+  let mut placed = Vec::with_capacity(query.len());
+  let mut i = 0;
+  for q in rest {
+    let Some(k) = (i..n).find(|&k| placeable(k, q)) else {
+      return false;
+    };
+    placed.push(k);
+    i = k + 1;
+  }
+  let Some(k) = (i.max(basename_start(path))..n).find(|&k| placeable(k, last)) else {
+    return false;
+  };
+  placed.push(k);
+
+  path.iter_mut().for_each(|p| {
+    p.in_query = false;
+    p.boundary = false;
+  });
+  for i in placed {
+    path[i].in_query = true;
+    path[i].boundary = word_start[i];
+  }
+  true
+}
+
+// keeps the basename and the directories holding a queried symbol, the others are cut down to their first symbol
+pub(super) fn abbreviate<S: Symbol>(path: &[PathNode<S>]) -> Vec<PathNode<S>> {
+  let basename = basename_start(path);
+  let mut res = Vec::with_capacity(path.len());
+  let mut start = 0;
+
+  while start < basename {
+    let end = path[start..basename].iter().position(|p| p.val.is_path_separator()).map_or(basename, |i| start + i + 1);
+    let segment = &path[start..end];
+
+    if segment.iter().any(|p| p.in_query) || segment.len() <= 2 {
+      res.extend_from_slice(segment);
+    } else {
+      res.push(segment[0].clone());
+      res.push(segment[segment.len() - 1].clone());
+    }
+
+    start = end;
+  }
+
+  res.extend_from_slice(&path[basename..]);
+  res
+}
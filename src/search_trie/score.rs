@@ -0,0 +1,98 @@
+use std::cmp::{Ordering, Reverse};
+
+use super::{config::{Order, SearchConfig}, engine::{Match, PathNode}, path, Symbol};
+
+const MATCH: i64 = 16;
+pub(super) const CONSECUTIVE: i64 = 8;
+const WORD_START: i64 = 12;
+const SEGMENT_START: i64 = 12;
+const BASENAME: i64 = 10;
+pub(super) const GAP_START: i64 = 3;
+pub(super) const GAP_EXTENSION: i64 = 1;
+
+// what a queried symbol at `i` scores on its own, more when it starts a word
+// or when (in path mode) it starts a path segment or is part of the basename
+pub(super) fn bonus<S: Symbol>(path: &[PathNode<S>], i: usize, boundary: bool, basename: usize, config: &SearchConfig) -> i64 {
+  let mut bonus = MATCH;
+  if boundary {
+    bonus += WORD_START;
+  }
+
+  if config.path.is_some() {
+    if path::is_segment_start(path, i) {
+      bonus += SEGMENT_START;
+    }
+    if i >= basename {
+      bonus += BASENAME;
+    }
+  }
+
+  bonus
+}
+
+// the penalty for the symbols skipped between two queried symbols
+pub(super) fn gap_penalty(gap: usize) -> i64 {
+  if gap == 0 { 0 } else { GAP_START + GAP_EXTENSION * (gap as i64 - 1) }
+}
+
+// higher is better, the bonus of each queried symbol plus `CONSECUTIVE` when it follows another one,
+// minus the penalty of the gap when it doesn't
+pub(super) fn score<S: Symbol>(path: &[PathNode<S>], config: &SearchConfig) -> i64 {
+  let basename = path::basename_start(path);
+  let mut score = 0;
+  let mut last = None;
+
+  for (i, node) in path.iter().enumerate() {
+    if !node.in_query {
+      continue;
+    }
+
+    score += bonus(path, i, node.boundary, basename, config);
+    match last {
+      Some(l) if l + 1 == i => score += CONSECUTIVE,
+      Some(l) => score -= gap_penalty(i - l - 1),
+      None => ()
+    }
+    last = Some(i);
+  }
+
+  score
+}
+
+// the score the engines give `line` with its queried symbols at `positions`, e.g. to compare with a placement of
+// their own
+pub fn score_positions<S: Symbol>(line: &[S], positions: &[usize], config: &SearchConfig) -> i64 {
+  let path: Vec<PathNode<S>> = line.iter().enumerate().map(|(i, c)| {
+    let in_query = positions.contains(&i);
+    let word_start = c.is_word_start(i.checked_sub(1).map(|p| &line[p]));
+    PathNode { val: c.clone(), in_query, boundary: in_query && word_start }
+  }).collect();
+
+  score(&path, config)
+}
+
+fn lexicographic<S: Symbol>(a: &Match<S>, b: &Match<S>) -> Ordering {
+  a.path.iter().map(|p| &p.val).cmp(b.path.iter().map(|p| &p.val))
+}
+
+// drops the matches the config rules out, scores the rest and sorts them in the order of the config
+pub(super) fn rank<S: Symbol>(matches: Vec<Match<S>>, config: &SearchConfig) -> Vec<Match<S>> {
+  let mut ranked: Vec<Match<S>> = matches.into_iter()
+    .filter(|m| match &config.path {
+      Some(mode) if mode.require_basename => path::last_in_basename(&m.path),
+      _ => true
+    })
+    .map(|mut m| {
+      m.score = score(&m.path, config);
+      m
+    })
+    .collect();
+
+  match config.order {
+    Order::Score => ranked.sort_by(|a, b| Reverse(a.score).cmp(&Reverse(b.score)).then_with(|| lexicographic(a, b))),
+    Order::Lexicographic => ranked.sort_by(lexicographic),
+    Order::Insertion => ranked.sort_by_key(|m| m.id),
+    Order::Length => ranked.sort_by(|a, b| a.path.len().cmp(&b.path.len()).then_with(|| lexicographic(a, b)))
+  }
+  ranked
+}
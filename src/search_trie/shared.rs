@@ -0,0 +1,230 @@
+use std::{io::BufRead, sync::{Arc, RwLock, RwLockReadGuard}};
+
+use crate::Result;
+
+use super::{read_lines, Engine, LineId, Match, OwnedEngine, OwnedTEngine, OwnedTPEngine, SearchConfig, SearchEngine, SearchTrie, Snapshot, Symbol, TEngine, TPEngine};
+
+// a trie which can take lines from any thread while engines search it, e.g. from a thread reading lines
+// as a slow command prints them. the engines search a snapshot and move to the latest one on the next keystroke
+// or `refresh`, inserts only wait for the snapshot to be taken, not for the search
+#[derive(Debug, Default)]
+pub struct SharedTrie<S: Symbol = char> {
+  trie: RwLock<SearchTrie<S>>
+}
+
+impl<S: Symbol> SharedTrie<S> {
+  pub fn new() -> Self {
+    Self { trie: RwLock::new(SearchTrie::new()) }
+  }
+
+  pub fn insert(&self, line: &str) -> LineId {
+    self.trie.write().unwrap().insert(line)
+  }
+
+  pub fn insert_symbols(&self, line: impl IntoIterator<Item = S>) -> LineId {
+    self.trie.write().unwrap().insert_symbols(line)
+  }
+
+  // inserts all the lines at once, the engines never see only some of them
+  pub fn extend<L: AsRef<str>>(&self, lines: impl IntoIterator<Item = L>) {
+    self.trie.write().unwrap().extend(lines);
+  }
+
+  // inserts the lines read from `reader` one at a time, so the engines see them as they are read, e.g. from a pipe.
+  // the number of lines read, see `SearchTrie::read_lines`
+  pub fn read_lines(&self, reader: impl BufRead) -> Result<usize> {
+    read_lines(reader, |line| {
+      self.insert_symbols(line);
+    })
+  }
+
+  pub fn remove(&self, line: &str) -> Option<LineId> {
+    self.trie.write().unwrap().remove(line)
+  }
+
+  pub fn remove_by_id(&self, id: LineId) -> Option<String> {
+    self.trie.write().unwrap().remove_by_id(id)
+  }
+
+  // the trie for everything else, the inserts wait until the guard is dropped
+  pub fn read(&self) -> RwLockReadGuard<'_, SearchTrie<S>> {
+    self.trie.read().unwrap()
+  }
+
+  pub fn len(&self) -> usize {
+    self.read().len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  // the latest snapshot with the version of the trie it was taken at
+  fn snapshot(&self) -> (u64, Snapshot<S>) {
+    let trie = self.read();
+    (trie.version(), trie.snapshot())
+  }
+
+  pub fn engine(self: &Arc<Self>) -> LiveEngine<OwnedEngine<S>, S> {
+    LiveEngine::new(self.clone(), Engine::new)
+  }
+
+  pub fn t_engine(self: &Arc<Self>) -> LiveEngine<OwnedTEngine<S>, S> {
+    LiveEngine::new(self.clone(), TEngine::new)
+  }
+
+  pub fn tp_engine(self: &Arc<Self>, thread_count: usize) -> LiveEngine<OwnedTPEngine<S>, S> {
+    LiveEngine::new(self.clone(), |root| TPEngine::new(root, thread_count))
+  }
+}
+
+impl<S: Symbol> From<SearchTrie<S>> for SharedTrie<S> {
+  fn from(trie: SearchTrie<S>) -> Self {
+    Self { trie: RwLock::new(trie) }
+  }
+}
+
+pub(super) mod sealed {
+  use super::{LineId, Symbol};
+
+  // only `LiveEngine` calls it, with the lines it found changed since the engine's snapshot. lines which aren't
+  // would throw the engine's counts and cached matches off
+  pub trait Update<S: Symbol> {
+    // takes in the lines added to and removed from the trie since its snapshot, with their ids, keeping the query
+    // and what it found: the engine still searches its snapshot and adds or takes out the changed lines
+    fn update(&mut self, added: &[(Vec<S>, LineId)], removed: &[(Vec<S>, LineId)]);
+  }
+}
+
+// the engines which can be moved to a newer snapshot of a trie. only implemented by the crate's engines, see
+// `sealed::Update`
+pub trait Rebase<S: Symbol>: sealed::Update<S> + SearchEngine<S> + Sized {
+  // starts over from the empty query on `root`, keeping the config (and the threads)
+  fn rebase(&mut self, root: Snapshot<S>);
+  fn with_config(self, config: SearchConfig) -> Self;
+}
+
+// changed lines with their ids
+type Changed<S> = Vec<(Vec<S>, LineId)>;
+
+// the lines of `new` which aren't in `old` and the other way around, with their ids. the nodes the two share are
+// skipped, so it only walks the paths of the lines changed since `old` was taken
+fn diff<S: Symbol>(old: &Snapshot<S>, new: &Snapshot<S>) -> (Changed<S>, Changed<S>) {
+  let (mut added, mut removed) = (Vec::new(), Vec::new());
+  let mut line = Vec::new();
+  // the length of the line leading to the parent, the symbol to the node and the node in each trie
+  let mut stack = vec![(0, None::<&S>, Some(old), Some(new))];
+
+  while let Some((depth, symbol, old, new)) = stack.pop() {
+    line.truncate(depth);
+    line.extend(symbol.cloned());
+    if let (Some(old), Some(new)) = (old, new) && Arc::ptr_eq(old, new) {
+      continue;
+    }
+
+    let (old_id, new_id) = (old.and_then(|node| node.id), new.and_then(|node| node.id));
+    if old_id != new_id {
+      removed.extend(old_id.map(|id| (line.clone(), id)));
+      added.extend(new_id.map(|id| (line.clone(), id)));
+    }
+
+    for (c, child) in new.iter().flat_map(|node| &node.val) {
+      stack.push((line.len(), Some(c), old.and_then(|node| node.val.get(c)), Some(child)));
+    }
+    for (c, child) in old.iter().flat_map(|node| &node.val) {
+      if !new.is_some_and(|node| node.val.contains_key(c)) {
+        stack.push((line.len(), Some(c), Some(child), None));
+      }
+    }
+  }
+
+  (added, removed)
+}
+
+// an engine searching a `SharedTrie`, which picks up the lines inserted and removed since its snapshot
+// before each keystroke. `matches`, `options` and `match_count` report the snapshot of the last keystroke
+// (or `refresh`) so they agree with each other
+pub struct LiveEngine<E, S: Symbol = char> {
+  trie: Arc<SharedTrie<S>>,
+  engine: E,
+  // the version of the trie the engine is up to date with, and its snapshot to find the lines changed after it
+  version: u64,
+  seen: Snapshot<S>,
+  // the lines of the snapshot the engine walks and how many lines changed since it was taken
+  walked: usize,
+  changed: usize,
+  query: Vec<S>
+}
+
+impl<E: Rebase<S>, S: Symbol> LiveEngine<E, S> {
+  fn new(trie: Arc<SharedTrie<S>>, engine: impl FnOnce(Snapshot<S>) -> E) -> Self {
+    let (version, root) = trie.snapshot();
+    let walked = root.ref_count as usize;
+    Self { trie, engine: engine(root.clone()), version, seen: root, walked, changed: 0, query: Vec::new() }
+  }
+
+  pub fn with_config(mut self, config: SearchConfig) -> Self {
+    self.engine = self.engine.with_config(config);
+    self.query.clear();
+    self
+  }
+
+  // brings the engine up to date with the latest snapshot when the trie changed, true when it did. the lines
+  // changed since the last refresh are added to or taken out of what each level of the query found, so the
+  // levels and the options they cached are kept. once more lines changed than the engine's snapshot holds, e.g.
+  // while a growing trie is streamed in, the engine moves to the latest snapshot and types the query again on it,
+  // which happens each time the trie doubles. when typing the query fails the next refresh starts over
+  pub fn refresh(&mut self) -> Result<bool> {
+    let (version, root) = self.trie.snapshot();
+    if version == self.version {
+      return Ok(false);
+    }
+
+    let (added, removed) = diff(&self.seen, &root);
+    self.changed += added.len() + removed.len();
+    if self.changed > self.walked {
+      self.engine.rebase(root.clone());
+      for c in &self.query {
+        self.engine.try_query(c.clone())?;
+      }
+      (self.walked, self.changed) = (root.ref_count as usize, 0);
+    } else {
+      self.engine.update(&added, &removed);
+    }
+
+    (self.version, self.seen) = (version, root);
+    Ok(true)
+  }
+}
+
+impl<E: Rebase<S>, S: Symbol> SearchEngine<S> for LiveEngine<E, S> {
+  fn try_query(&mut self, input: S) -> Result<()> {
+    self.refresh()?;
+    self.engine.try_query(input.clone())?;
+    self.query.push(input);
+    Ok(())
+  }
+
+  fn try_backspace(&mut self) -> Result<()> {
+    self.refresh()?;
+    self.engine.try_backspace()?;
+    self.query.pop();
+    Ok(())
+  }
+
+  fn try_matches(&self) -> Result<Vec<Match<S>>> {
+    self.engine.try_matches()
+  }
+
+  fn try_ranked(&self) -> Result<Arc<Vec<Match<S>>>> {
+    self.engine.try_ranked()
+  }
+
+  fn match_count(&self) -> usize {
+    self.engine.match_count()
+  }
+
+  fn config(&self) -> &SearchConfig {
+    self.engine.config()
+  }
+}
@@ -0,0 +1,116 @@
+use std::{collections::BTreeMap, mem::size_of, sync::Arc};
+
+use super::{LineId, SearchTrie, Symbol, TrieNode};
+
+// the shape and size of a trie, see `SearchTrie::stats`
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TrieStats {
+  // every node, the root included
+  pub nodes: usize,
+  // the nodes where a line ends, one per line
+  pub terminals: usize,
+  // the depth of the deepest node, the length in symbols of the longest line
+  pub max_depth: usize,
+  // the mean depth of the terminal nodes, the mean length of the lines in symbols
+  pub mean_depth: f64,
+  // how many nodes have each number of children, the leaves under 0
+  pub branching: BTreeMap<usize, usize>,
+  // estimated heap bytes of the nodes: their allocations with the reference counts, the hash tables of their
+  // children (with the control bytes and the free buckets) and what the symbols own
+  pub node_bytes: usize,
+  // estimated heap bytes of the symbols of each line kept to find the lines by id
+  pub line_bytes: usize
+}
+
+impl TrieStats {
+  pub fn heap_bytes(&self) -> usize {
+    self.node_bytes + self.line_bytes
+  }
+}
+
+// the buckets of a `HashMap` holding `capacity` entries: a power of two kept at most 7/8 full
+fn buckets(capacity: usize) -> usize {
+  match capacity {
+    0 => 0,
+    1..4 => 4,
+    4..8 => 8,
+    _ => (capacity * 8 / 7).next_power_of_two()
+  }
+}
+
+// the control bytes a `HashMap` compares at once, as its hashbrown implementation picks them for the target:
+// 16 with SSE2, 8 with NEON and a machine word otherwise
+const GROUP_WIDTH: usize = if cfg!(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2")) {
+  16
+} else if cfg!(all(target_arch = "aarch64", target_feature = "neon")) {
+  8
+} else {
+  size_of::<usize>()
+};
+
+// the bytes the table of a `HashMap<K, V>` with `capacity` takes: the entries and a control byte per bucket,
+// plus a group of control bytes mirrored at the end
+fn table_bytes<K, V>(capacity: usize) -> usize {
+  match buckets(capacity) {
+    0 => 0,
+    buckets => buckets * (size_of::<(K, V)>() + 1) + GROUP_WIDTH
+  }
+}
+
+// a `BTreeMap<K, V>` of `len` entries: ids are inserted in increasing order, which splits each full leaf of
+// 11 entries into one of 6 and one of 4, so there is a leaf for about every 7 entries and an internal node
+// (a leaf with 12 edges) for about every 7 leaves
+fn btree_bytes<K, V>(len: usize) -> usize {
+  let leaf = 2 * size_of::<usize>() + 11 * (size_of::<K>() + size_of::<V>());
+  let internal = leaf + 12 * size_of::<usize>();
+  match len {
+    0 => 0,
+    1..=11 => leaf,
+    _ => len.div_ceil(7) * leaf + len.div_ceil(49) * internal
+  }
+}
+
+impl<S: Symbol> SearchTrie<S> {
+  // walks every node, with its own stack so very long lines don't overflow it. the nodes shared with
+  // snapshots are counted as this trie's
+  pub fn stats(&self) -> TrieStats {
+    let mut stats = TrieStats {
+      nodes: 0,
+      terminals: 0,
+      max_depth: 0,
+      mean_depth: 0.0,
+      branching: BTreeMap::new(),
+      node_bytes: 0,
+      line_bytes: 0
+    };
+    let mut depths = 0;
+    let mut stack = vec![(&*self.root, 0)];
+
+    while let Some((node, depth)) = stack.pop() {
+      stats.nodes += 1;
+      *stats.branching.entry(node.val.len()).or_default() += 1;
+      stats.max_depth = stats.max_depth.max(depth);
+      if node.id.is_some() {
+        stats.terminals += 1;
+        depths += depth;
+      }
+
+      // the `Arc` holding the node with its strong and weak counts
+      stats.node_bytes += 2 * size_of::<usize>() + size_of::<TrieNode<S>>();
+      stats.node_bytes += table_bytes::<S, Arc<TrieNode<S>>>(node.val.capacity());
+      for (c, child) in &node.val {
+        stats.node_bytes += c.heap_bytes();
+        stack.push((child, depth + 1));
+      }
+    }
+
+    if stats.terminals > 0 {
+      stats.mean_depth = depths as f64 / stats.terminals as f64;
+    }
+    stats.line_bytes = btree_bytes::<LineId, Vec<S>>(self.lines.len())
+      + self.lines.values().map(|line| line.capacity() * size_of::<S>() + line.iter().map(S::heap_bytes).sum::<usize>()).sum::<usize>();
+
+    stats
+  }
+}
@@ -0,0 +1,208 @@
+use std::{fmt::Debug, hash::Hash};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+// a unit of the key alphabet stored along the edges of the trie
+pub trait Symbol: Clone + Eq + Ord + Hash + Debug + Send + Sync {
+  // splits a line into the symbols stored along its path in the trie
+  fn split(line: &str) -> Vec<Self>;
+
+  // splits a line read as bytes, e.g. by `SearchTrie::read_lines`. the bytes which aren't valid UTF-8 are replaced
+  // unless the symbols can hold them
+  fn split_bytes(line: &[u8]) -> Vec<Self> {
+    Self::split(&String::from_utf8_lossy(line))
+  }
+
+  // appends the textual form of the symbol to `out`
+  fn render(&self, out: &mut String);
+
+  // whether the symbol separates the segments of a file path
+  fn is_path_separator(&self) -> bool {
+    false
+  }
+
+  // whether the symbol starts a word when it follows `prev`
+  fn is_word_start(&self, prev: Option<&Self>) -> bool {
+    prev.is_none()
+  }
+
+  // whether a queried symbol matches this symbol of a line with smart case, see `Case::Smart`. a symbol without
+  // a case only matches itself
+  fn is_matched_by(&self, query: &Self) -> bool {
+    self == query
+  }
+
+  // the bytes the symbol owns on the heap, for `SearchTrie::stats`
+  fn heap_bytes(&self) -> usize {
+    0
+  }
+}
+
+// a word starts at the start of the line, after a separator or on a lower to upper case transition
+fn is_char_word_start(curr: char, prev: Option<char>) -> bool {
+  match prev {
+    None => true,
+    Some(p) if matches!(p, '_' | '-' | '.' | '/') || p.is_whitespace() || p == std::path::MAIN_SEPARATOR => true,
+    Some(p) => p.is_lowercase() && curr.is_uppercase()
+  }
+}
+
+impl Symbol for char {
+  fn split(line: &str) -> Vec<Self> {
+    line.chars().collect()
+  }
+
+  fn render(&self, out: &mut String) {
+    out.push(*self);
+  }
+
+  fn is_path_separator(&self) -> bool {
+    *self == '/' || *self == std::path::MAIN_SEPARATOR
+  }
+
+  fn is_word_start(&self, prev: Option<&Self>) -> bool {
+    is_char_word_start(*self, prev.copied())
+  }
+
+  // a lowercase query matches either case
+  fn is_matched_by(&self, query: &Self) -> bool {
+    self == query || (query.is_lowercase() && self.to_lowercase().eq(query.to_lowercase()))
+  }
+}
+
+// raw bytes, so lines which are not valid UTF-8 (e.g. filenames) can be indexed through `insert_symbols`
+impl Symbol for u8 {
+  fn split(line: &str) -> Vec<Self> {
+    line.as_bytes().to_vec()
+  }
+
+  fn split_bytes(line: &[u8]) -> Vec<Self> {
+    line.to_vec()
+  }
+
+  // non-ASCII bytes are escaped since a single byte of a multi-byte sequence can't be rendered on its own
+  fn render(&self, out: &mut String) {
+    if self.is_ascii() {
+      out.push(*self as char);
+    } else {
+      out.push_str(&format!("\\x{:02X}", self));
+    }
+  }
+
+  fn is_path_separator(&self) -> bool {
+    *self == b'/' || *self as char == std::path::MAIN_SEPARATOR
+  }
+
+  // only ASCII bytes start words, and only after ASCII ones: read as Latin-1 the bytes of a multi-byte sequence
+  // would make up case transitions, e.g. the `Ã` (0xC3) leading `é` after a lowercase letter
+  fn is_word_start(&self, prev: Option<&Self>) -> bool {
+    match prev {
+      _ if !self.is_ascii() => false,
+      Some(p) if !p.is_ascii() => false,
+      _ => is_char_word_start(*self as char, prev.map(|p| *p as char))
+    }
+  }
+
+  fn is_matched_by(&self, query: &Self) -> bool {
+    self == query || (query.is_ascii_lowercase() && self.to_ascii_lowercase() == *query)
+  }
+}
+
+// an extended grapheme cluster, so emoji and combining sequences are matched as a single symbol
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Grapheme(pub String);
+
+impl From<&str> for Grapheme {
+  fn from(value: &str) -> Self {
+    Self(value.to_owned())
+  }
+}
+
+impl Symbol for Grapheme {
+  fn split(line: &str) -> Vec<Self> {
+    line.graphemes(true).map(Grapheme::from).collect()
+  }
+
+  fn render(&self, out: &mut String) {
+    out.push_str(&self.0);
+  }
+
+  fn is_path_separator(&self) -> bool {
+    self.0 == "/" || self.0 == std::path::MAIN_SEPARATOR_STR
+  }
+
+  fn is_word_start(&self, prev: Option<&Self>) -> bool {
+    match self.0.chars().next() {
+      Some(c) => is_char_word_start(c, prev.and_then(|p| p.0.chars().last())),
+      None => false
+    }
+  }
+
+  fn is_matched_by(&self, query: &Self) -> bool {
+    self == query || (query.0 == query.0.to_lowercase() && self.0.to_lowercase() == query.0)
+  }
+
+  fn heap_bytes(&self) -> usize {
+    self.0.capacity()
+  }
+}
+
+// a whole word or path segment, for token level search.
+// runs of alphanumeric chars form one token and every other char is a token of its own,
+// so rendering the tokens back to back restores the original line
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Token(pub String);
+
+impl From<&str> for Token {
+  fn from(value: &str) -> Self {
+    Self(value.to_owned())
+  }
+}
+
+impl Symbol for Token {
+  fn split(line: &str) -> Vec<Self> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+
+    for c in line.chars() {
+      if c.is_alphanumeric() {
+        word.push(c);
+        continue;
+      }
+
+      if !word.is_empty() {
+        tokens.push(Token(std::mem::take(&mut word)));
+      }
+      tokens.push(Token(c.to_string()));
+    }
+
+    if !word.is_empty() {
+      tokens.push(Token(word));
+    }
+
+    tokens
+  }
+
+  fn render(&self, out: &mut String) {
+    out.push_str(&self.0);
+  }
+
+  fn is_path_separator(&self) -> bool {
+    self.0 == "/" || self.0 == std::path::MAIN_SEPARATOR_STR
+  }
+
+  fn is_word_start(&self, prev: Option<&Self>) -> bool {
+    match self.0.chars().next() {
+      Some(c) => is_char_word_start(c, prev.and_then(|p| p.0.chars().last())),
+      None => false
+    }
+  }
+
+  fn is_matched_by(&self, query: &Self) -> bool {
+    self == query || (query.0 == query.0.to_lowercase() && self.0.to_lowercase() == query.0)
+  }
+
+  fn heap_bytes(&self) -> usize {
+    self.0.capacity()
+  }
+}
@@ -1,96 +1,110 @@
-use std::{sync::mpsc::channel, thread::scope};
+use std::{sync::{mpsc::channel, Arc}, thread::scope};
 
-use super::engine::{build_path, expand, traverse};
-use super::SearchEngine;
-use super::{engine::HistoryNode, TrieNode};
+use crate::Result;
 
-pub struct TEngine<'a> {
-  // store the different current Trie_nodes reached and PathNodes to build the line with history for backspace
-  history: Vec<Vec<HistoryNode<'a>>>,
+use super::engine::{align_lines, count_matches, gather, node_matches, work, Match, NodeRef};
+use super::{config::SearchConfig, history::History, score::rank, shared::{sealed::Update, Rebase}, LineId, SearchEngine, Snapshot, Symbol};
+use super::engine::HistoryNode;
+
+pub struct TEngine<N, S: Symbol = char> {
+  // the nodes reached after each queried symbol, kept for backspace
+  history: History<N, S>,
+  config: SearchConfig
 }
 
-impl<'a> TEngine<'a> {
-  pub fn new(root: &'a TrieNode) -> Self {
+impl<N: NodeRef<S>, S: Symbol> TEngine<N, S> {
+  pub fn new(root: N) -> Self {
     Self { 
-      history:  vec![vec![HistoryNode { node: root, path: Vec::new() }]],
+      history: History::new(root),
+      config: SearchConfig::default()
     }
   }
+
+  pub fn with_config(mut self, config: SearchConfig) -> Self {
+    self.config = config;
+    self.history.reset();
+    self
+  }
 }
 
-impl<'a> SearchEngine for TEngine<'a> {
-  fn query(&mut self, input: char) {
-    if input.eq(&'*') {
-      if self.history.len() > 1 {
-        self.history.pop();
-      }
-      return;
+impl<N: NodeRef<S>, S: Symbol> SearchEngine<S> for TEngine<N, S> {
+  fn try_query(&mut self, input: S) -> Result<()> {
+    if self.history.restore(&input) || work(|| self.history.filter(&input, &self.config))? {
+      return Ok(());
     }
 
-    let curr = self.history.last().unwrap().to_vec();
-    let mut next = Vec::new();
-    let (tx, tr) = channel();
+    let curr = self.history.nodes();
+    let target = &input;
+    let config = &self.config;
 
-    scope(|s| {
-      let mut threads = Vec::new();
-      for HistoryNode {node, path } in curr {
+    let next = scope(|s| {
+      let (tx, tr) = channel();
+      for node in curr {
         let tx_c = tx.clone();
-        threads.push( s.spawn(move || {
-          tx_c.send(
-            traverse(node, input, None).iter_mut().map(|hp| {
-              let mut t_path = path.to_vec();
-              t_path.append(&mut hp.path);
-              HistoryNode {node: hp.node, path: t_path }
-            }).collect()
-          ).unwrap();
-        }));
+        s.spawn(move || {
+          // the receiver is only gone when another worker failed
+          let _ = tx_c.send(work(|| node.next(target, config)));
+        });
       }
 
-      for _ in threads {
-        next.append(&mut tr.recv().unwrap());
-      }
-    });
+      drop(tx);
+      gather(tr, curr.len())
+    })?;
     
-    self.history.push(next);
+    work(|| self.history.push(input, next, &self.config))
   }
 
-  fn options(&self) -> Vec<String> {
-    let mut options_list = Vec::new();
-
-    if let Some(nodes) = self.history.last() {
-      scope(|s| {
-        let mut threads = Vec::new();
-        let (tx, tr) = channel();
-
-        for HistoryNode { node, path } in nodes {
-          let tx_c = tx.clone();
-          threads.push(
-            s.spawn(move || {
-              let path1 = build_path(path);
-              let mut local_opts = Vec::new();
-  
-              let path2_list = expand(node);
-              
-              if node.end {
-                local_opts.push(path1.clone());
-              }
-
-              path2_list.iter().for_each(|path2| {
-                local_opts.push(format!("{}{}", path1, path2));
-              });
-            
-              tx_c.send(local_opts).unwrap();
-            })
-          );
-
-        }
-
-        for _ in threads {
-          options_list.append(&mut tr.recv().unwrap());
-        }
-      });
+  fn try_backspace(&mut self) -> Result<()> {
+    self.history.pop();
+    Ok(())
+  }
 
+  fn try_matches(&self) -> Result<Vec<Match<S>>> {
+    if let Some(lines) = self.history.lines() {
+      return work(|| align_lines(lines, self.history.query(), &self.config));
     }
 
-    options_list
+    let (nodes, query, config) = (self.history.nodes(), self.history.query(), &self.config);
+    scope(|s| {
+      let (tx, tr) = channel();
+
+      for HistoryNode { node, path } in nodes {
+        let tx_c = tx.clone();
+        s.spawn(move || {
+          let _ = tx_c.send(work(|| node_matches(node, path, query, config)));
+        });
+      }
+
+      drop(tx);
+      gather(tr, nodes.len())
+    }).and_then(|matches| work(|| self.history.patch(matches, config)))
+  }
+
+  fn try_ranked(&self) -> Result<Arc<Vec<Match<S>>>> {
+    self.history.ranked(|| Ok(rank(self.try_matches()?, &self.config)))
   }
-}
\ No newline at end of file
+
+  fn match_count(&self) -> usize {
+    count_matches(&self.history, &self.config)
+  }
+
+  fn config(&self) -> &SearchConfig {
+    &self.config
+  }
+}
+
+impl<S: Symbol> Update<S> for TEngine<Snapshot<S>, S> {
+  fn update(&mut self, added: &[(Vec<S>, LineId)], removed: &[(Vec<S>, LineId)]) {
+    self.history.update(added, removed, &self.config);
+  }
+}
+
+impl<S: Symbol> Rebase<S> for TEngine<Snapshot<S>, S> {
+  fn rebase(&mut self, root: Snapshot<S>) {
+    self.history = History::new(root);
+  }
+
+  fn with_config(self, config: SearchConfig) -> Self {
+    TEngine::with_config(self, config)
+  }
+}
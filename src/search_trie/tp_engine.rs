@@ -1,100 +1,122 @@
-use std::sync::mpsc::channel;
+use std::sync::{mpsc::channel, Arc};
 
 use threadpool::ThreadPool;
 use threadpool_scope::scope_with;
 
-use super::{engine::{HistoryNode, build_path, expand, traverse}, SearchEngine, TrieNode};
+use crate::{Error, Result};
 
-pub struct TPEngine<'a> {
-  // store the different current Trie_nodes reached and PathNodes to build the line with history for backspace
-  history: Vec<Vec<HistoryNode<'a>>>,
-  threads: ThreadPool
+use super::{config::SearchConfig, engine::{align_lines, count_matches, gather, HistoryNode, Match, node_matches, NodeRef, work}, history::History, score::rank, shared::{sealed::Update, Rebase}, LineId, SearchEngine, Snapshot, Symbol};
+
+pub struct TPEngine<N, S: Symbol = char> {
+  // the nodes reached after each queried symbol, kept for backspace
+  history: History<N, S>,
+  threads: ThreadPool,
+  config: SearchConfig
 }
 
-impl<'a> TPEngine<'a> {
-  pub fn new(root: &'a TrieNode, thread_count: usize) -> Self {
-    Self { 
-      history:  vec![vec![HistoryNode { node: root, path: Vec::new() }]],
-      threads: ThreadPool::new(thread_count)
+impl<N: NodeRef<S>, S: Symbol> TPEngine<N, S> {
+  // panics when `thread_count` is 0, see `try_new`
+  pub fn new(root: N, thread_count: usize) -> Self {
+    Self::try_new(root, thread_count).unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  pub fn try_new(root: N, thread_count: usize) -> Result<Self> {
+    if thread_count == 0 {
+      return Err(Error::InvalidValue { what: "thread count", value: thread_count.to_string() });
     }
+
+    Ok(Self {
+      history: History::new(root),
+      threads: ThreadPool::new(thread_count),
+      config: SearchConfig::default()
+    })
+  }
+
+  pub fn with_config(mut self, config: SearchConfig) -> Self {
+    self.config = config;
+    self.history.reset();
+    self
   }
-  
 }
 
-impl<'a> SearchEngine for TPEngine<'a> {  
-  fn query(&mut self, input: char) {
-    if input.eq(&'*') {
-      if self.history.len() > 1 {
-        self.history.pop();
-      }
-      return;
+impl<N: NodeRef<S>, S: Symbol> SearchEngine<S> for TPEngine<N, S> {
+  fn try_query(&mut self, input: S) -> Result<()> {
+    if self.history.restore(&input) || work(|| self.history.filter(&input, &self.config))? {
+      return Ok(());
     }
 
-    let curr = self.history.last().unwrap().to_vec();
-    let mut next = Vec::new();
-    let (tx, tr) = channel();
+    let curr = self.history.nodes();
+    let target = &input;
+    let config = &self.config;
 
-    scope_with(&self.threads, |s| {
-      let mut threads = Vec::new();
-      for HistoryNode {node, path } in curr {
+    let next = scope_with(&self.threads, |s| {
+      let (tx, tr) = channel();
+      for node in curr {
         let tx_c = tx.clone();
-        threads.push( s.execute(move || {
-          tx_c.send(
-          traverse(node, input, None).iter_mut().map(|hp| {
-            let mut t_path = path.to_vec();
-            t_path.append(&mut hp.path);
-            HistoryNode {node: hp.node, path: t_path }
-          }).collect()
-        ).unwrap();
-        }));
+        s.execute(move || {
+          // the receiver is only gone when another worker failed
+          let _ = tx_c.send(work(|| node.next(target, config)));
+        });
       }
 
-      for _ in threads {
-        next.append(&mut tr.recv().unwrap());
-      }
-    });
+      drop(tx);
+      gather(tr, curr.len())
+    })?;
     
-    self.history.push(next);
+    work(|| self.history.push(input, next, &self.config))
   }
 
-  fn options(&self) -> Vec<String> {
-    let mut options_list = Vec::new();
-
-    if let Some(nodes) = self.history.last() {
-      scope_with(&self.threads ,|s| {
-        let mut threads = Vec::new();
-        let (tx, tr) = channel();
-
-        for HistoryNode { node, path } in nodes {
-          let tx_c = tx.clone();
-          threads.push(
-            s.execute(move || {
-              let path1 = build_path(path);
-              let mut local_opts = Vec::new();
-  
-              let path2_list = expand(node);
-              
-              if node.end {
-                local_opts.push(path1.clone());
-              }
-
-              path2_list.iter().for_each(|path2| {
-                local_opts.push(format!("{}{}", path1, path2));
-              });
-  
-              tx_c.send(local_opts).unwrap();
-            })
-          );
-
-        }
-
-        for _ in threads {
-          options_list.append(&mut tr.recv().unwrap());
-        }
-      });
+  fn try_backspace(&mut self) -> Result<()> {
+    self.history.pop();
+    Ok(())
+  }
 
+  fn try_matches(&self) -> Result<Vec<Match<S>>> {
+    if let Some(lines) = self.history.lines() {
+      return work(|| align_lines(lines, self.history.query(), &self.config));
     }
 
-    options_list
+    let (nodes, query, config) = (self.history.nodes(), self.history.query(), &self.config);
+    scope_with(&self.threads, |s| {
+      let (tx, tr) = channel();
+
+      for HistoryNode { node, path } in nodes {
+        let tx_c = tx.clone();
+        s.execute(move || {
+          let _ = tx_c.send(work(|| node_matches(node, path, query, config)));
+        });
+      }
+
+      drop(tx);
+      gather(tr, nodes.len())
+    }).and_then(|matches| work(|| self.history.patch(matches, config)))
   }
-}
\ No newline at end of file
+
+  fn try_ranked(&self) -> Result<Arc<Vec<Match<S>>>> {
+    self.history.ranked(|| Ok(rank(self.try_matches()?, &self.config)))
+  }
+
+  fn match_count(&self) -> usize {
+    count_matches(&self.history, &self.config)
+  }
+
+  fn config(&self) -> &SearchConfig {
+    &self.config
+  }
+}
+
+impl<S: Symbol> Update<S> for TPEngine<Snapshot<S>, S> {
+  fn update(&mut self, added: &[(Vec<S>, LineId)], removed: &[(Vec<S>, LineId)]) {
+    self.history.update(added, removed, &self.config);
+  }
+}
+
+impl<S: Symbol> Rebase<S> for TPEngine<Snapshot<S>, S> {
+  fn rebase(&mut self, root: Snapshot<S>) {
+    self.history = History::new(root);
+  }
+
+  fn with_config(self, config: SearchConfig) -> Self {
+    TPEngine::with_config(self, config)
+  }
+}
@@ -0,0 +1,166 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+use serde_json::Value;
+
+const LINES: &str = "src/main.rs\nsrc/lib.rs\nREADME.md\nlib/mod.rs\n";
+
+// the exit code, stdout and stderr of `cli_search` on `input`
+fn cli(input: impl AsRef<[u8]>, args: &[&str]) -> (i32, String, String) {
+  let mut child = Command::new(env!("CARGO_BIN_EXE_cli_search"))
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .unwrap();
+  // the process may exit on an invalid argument before reading any of it
+  let _ = child.stdin.take().unwrap().write_all(input.as_ref());
+
+  let output = child.wait_with_output().unwrap();
+  (output.status.code().unwrap(), String::from_utf8(output.stdout).unwrap(), String::from_utf8(output.stderr).unwrap())
+}
+
+// the exit code and stdout of `cli_search filter` on `input`
+fn filter(input: &str, args: &[&str]) -> (i32, String) {
+  let (code, stdout, _) = cli(input, &[&["filter"], args].concat());
+  (code, stdout)
+}
+
+#[test]
+fn filter_prints_the_ranked_matches() {
+  assert_eq!(filter(LINES, &["lib"]), (0, "lib/mod.rs\nsrc/lib.rs\n".to_owned()));
+  assert_eq!(filter(LINES, &["lib", "--limit", "1"]), (0, "lib/mod.rs\n".to_owned()));
+  assert_eq!(filter(LINES, &["lib", "--order", "insertion"]), (0, "src/lib.rs\nlib/mod.rs\n".to_owned()));
+  // the empty query matches every line
+  assert_eq!(filter(LINES, &[""]).1.lines().count(), 4);
+
+  for engine in ["engine", "tengine", "tpengine"] {
+    assert_eq!(filter(LINES, &["lib", "--engine", engine, "--threads", "2"]), filter(LINES, &["lib"]), "{}", engine);
+  }
+}
+
+#[test]
+fn filter_takes_the_query_after_the_options() {
+  assert_eq!(filter(LINES, &["--limit", "2", "zzz"]), (1, String::new()));
+  assert_eq!(filter(LINES, &["--limit", "1", "--path", "lib"]), filter(LINES, &["lib", "--limit", "1", "--path"]));
+  assert_eq!(filter(LINES, &["--order=insertion", "lib"]), filter(LINES, &["lib", "--order", "insertion"]));
+}
+
+#[test]
+fn filter_exits_with_1_without_matches() {
+  assert_eq!(filter(LINES, &["zzz"]), (1, String::new()));
+  assert_eq!(filter("", &["a"]), (1, String::new()));
+  // matching is case sensitive unless asked for smart case
+  assert_eq!(filter("getSearchValue\n", &["gsv"]).0, 1);
+  assert_eq!(filter("getSearchValue\n", &["gsv", "--smart-case"]).0, 0);
+  assert_eq!(filter("getSearchValue\n", &["GSV", "--smart-case"]).0, 1);
+  // acronym mode only matches at word starts
+  assert_eq!(filter("getSearchValue\n", &["--acronym", "--smart-case", "gsv"]).0, 0);
+  assert_eq!(filter("gossip\n", &["gsv", "--acronym", "--smart-case"]).0, 1);
+}
+
+#[test]
+fn filter_formats() {
+  let args = |format: &'static str| vec!["lib", "--order", "insertion", "--path", "--format", format];
+
+  assert_eq!(filter(LINES, &args("highlight")).1, "s/-l--i--b-.rs\n-l--i--b-/mod.rs\n");
+  assert_eq!(filter(LINES, &args("nul")).1, "src/lib.rs\0lib/mod.rs\0");
+  assert_eq!(filter(LINES, &args("ansi")).1.lines().next().unwrap(), "s/\x1b[1;31ml\x1b[0m\x1b[1;31mi\x1b[0m\x1b[1;31mb\x1b[0m.rs");
+
+  let mut markers = args("highlight");
+  markers.extend(["--mark-start", "<", "--mark-end", ">"]);
+  assert_eq!(filter(LINES, &markers).1, "s/<l><i><b>.rs\n<l><i><b>/mod.rs\n");
+
+  let jsonl: Vec<Value> = filter(LINES, &args("jsonl")).1.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+  assert_eq!(jsonl.len(), 2);
+  assert_eq!(jsonl[0]["line"], "src/lib.rs");
+  assert_eq!(jsonl[0]["positions"], serde_json::json!([4, 5, 6]));
+  assert_eq!(jsonl[0]["id"], 1);
+  assert!(jsonl[0]["score"].as_i64().unwrap() > 0);
+}
+
+#[test]
+fn errors_exit_with_their_own_code() {
+  for (args, code, message) in [
+    (&["nowhere"][..], 2, "invalid command: nowhere"),
+    (&["filter", "a", "--limit", "many"], 2, "invalid limit: many"),
+    (&["filter", "a", "--engine", "tpengine", "--threads", "0"], 2, "invalid thread count: 0"),
+    (&["filter", "a", "--format", "xml"], 2, "invalid format: xml"),
+    (&["filter", "--limit", "2"], 2, "missing QUERY"),
+    (&["gen", "--alphabet="], 2, "invalid alphabet"),
+    (&["gen", "--shared-prefix", "nan"], 2, "invalid shared prefix: NaN"),
+    (&["gen", "--length", "50~inf"], 2, "invalid length: 50~inf"),
+    (&["gen", "--length", "18446744073709551615"], 2, "invalid length: 18446744073709551615"),
+    (&["gen", "--length", "1e30~1"], 2, "invalid length: 1e30~1"),
+    (&["interactive", "--input", "/nonexistent/lines"], 3, "No such file"),
+  ] {
+    let (status, _, stderr) = cli("a\n", args);
+    assert_eq!(status, code, "{:?}", args);
+    assert!(stderr.contains(message), "{:?} printed {}", args, stderr);
+  }
+}
+
+#[test]
+fn interactive_skips_empty_lines_and_stops_at_the_end_of_the_input() {
+  let (status, _, stderr) = cli("\n  \nA\n\n*\nB\n", &["interactive", "--count", "20", "--format", "plain"]);
+  assert_eq!(status, 0);
+  // the options are listed after each of the three keystrokes only
+  assert_eq!(stderr.matches("matches: ").count(), 3);
+}
+
+#[test]
+fn filter_reads_crlf_and_invalid_utf8() {
+  let (status, stdout, _) = cli(b"one\r\ntw\xffo\r\ntwo", &["filter", "o", "--order", "insertion"]);
+  assert_eq!(status, 0);
+  assert_eq!(stdout, "one\ntw\u{fffd}o\ntwo\n");
+}
+
+#[test]
+fn stats_of_the_corpus() {
+  let (status, stdout, _) = cli("", &["stats", "--count", "100", "--format", "json"]);
+  assert_eq!(status, 0);
+  let stats: Value = serde_json::from_str(&stdout).unwrap();
+  assert_eq!(stats["lines"], stats["terminals"]);
+  assert!(stats["nodes"].as_u64().unwrap() > 100);
+  assert_eq!(stats["heap_bytes"].as_u64(), Some(stats["node_bytes"].as_u64().unwrap() + stats["line_bytes"].as_u64().unwrap()));
+
+  let (status, stdout, _) = cli("", &["stats", "--count", "100"]);
+  assert_eq!(status, 0);
+  assert!(stdout.starts_with("lines"), "{}", stdout);
+  assert_eq!(cli("", &["stats", "--format", "xml"]).0, 2);
+}
+
+#[test]
+fn bench_formats_quote_the_keys() {
+  let args = |format| vec!["bench", "--count", "20", "--query", "A,\"", "--warmup", "0", "--runs", "1", "--engines", "engine", "--format", format];
+
+  let (status, csv, _) = cli("", &args("csv"));
+  assert_eq!(status, 0);
+  let rows: Vec<&str> = csv.lines().collect();
+  assert_eq!(rows.len(), 7);
+  assert!(rows[1].starts_with("Engine,,0,A,query,"), "{}", csv);
+  assert!(rows[3].starts_with("Engine,,1,\",\",query,"), "{}", csv);
+  assert!(rows[5].starts_with("Engine,,2,\"\"\"\",query,"), "{}", csv);
+
+  let (status, json, _) = cli("", &args("json"));
+  assert_eq!(status, 0);
+  let json: Value = serde_json::from_str(&json).unwrap();
+  let keys: Vec<&str> = json[0]["keystrokes"].as_array().unwrap().iter().map(|k| k["key"].as_str().unwrap()).collect();
+  assert_eq!(keys, ["A", ",", "\""]);
+  assert_eq!(json[0]["threads"], Value::Null);
+}
+
+#[test]
+fn inputs_are_read_like_stdin() {
+  let input = std::env::temp_dir().join(format!("cli_search_input_{}", std::process::id()));
+  std::fs::write(&input, b"one\r\ntw\xffo\r\ntwo").unwrap();
+  let path = input.to_str().unwrap();
+
+  let (status, stdout, stderr) = cli("", &["stats", "--input", path, "--format", "json"]);
+  assert_eq!(status, 0, "{}", stderr);
+  assert_eq!(serde_json::from_str::<Value>(&stdout).unwrap()["lines"], 3);
+  let (status, _, stderr) = cli("", &["bench", "--input", path, "--query", "o", "--warmup", "0", "--runs", "1", "--engines", "engine"]);
+  assert_eq!(status, 0, "{}", stderr);
+
+  std::fs::remove_file(&input).unwrap();
+}
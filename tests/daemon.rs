@@ -0,0 +1,181 @@
+use std::{io::{BufRead, BufReader, Write}, os::unix::net::UnixStream, path::PathBuf, sync::Arc, thread, time::Duration};
+
+use serde_json::{json, Value};
+
+use cli_search::daemon::{Client, Daemon};
+
+// a daemon listening on a socket of its own, and a client connected to it
+fn start(name: &str) -> (PathBuf, Client) {
+  let socket = std::env::temp_dir().join(format!("cli_search_{}_{}.sock", name, std::process::id()));
+  let path = socket.clone();
+  thread::spawn(move || Arc::new(Daemon::new()).serve(path));
+
+  for _ in 0..100 {
+    if let Ok(client) = Client::connect(&socket) {
+      return (socket, client);
+    }
+    thread::sleep(Duration::from_millis(10));
+  }
+  panic!("the daemon didn't start listening on {}", socket.display());
+}
+
+fn ok(client: &mut Client, request: Value) -> Value {
+  let response = client.request(&request).unwrap();
+  assert_eq!(response["ok"], true, "{} failed: {}", request, response);
+  response
+}
+
+fn lines(options: &Value) -> Vec<&str> {
+  options["options"].as_array().unwrap().iter().map(|o| o["line"].as_str().unwrap()).collect()
+}
+
+#[test]
+fn sessions_search_the_named_tries() {
+  let (socket, mut client) = start("sessions");
+
+  assert_eq!(ok(&mut client, json!({ "cmd": "load", "trie": "files", "lines": ["src/main.rs", "src/lib.rs", "README.md"] }))["count"], 3);
+  ok(&mut client, json!({ "cmd": "load", "trie": "words", "lines": ["alpha", "beta"] }));
+
+  let files = ok(&mut client, json!({ "cmd": "open", "trie": "files", "path": true }))["session"].clone();
+  // another connection sees the same tries, its sessions have their own query
+  let mut other = Client::connect(&socket).unwrap();
+  let words = ok(&mut other, json!({ "cmd": "open", "trie": "words" }))["session"].clone();
+
+  assert_eq!(ok(&mut client, json!({ "cmd": "query", "session": files, "query": "rs" }))["count"], 2);
+  assert_eq!(ok(&mut other, json!({ "cmd": "query", "session": words, "query": "a" }))["count"], 2);
+
+  let options = ok(&mut client, json!({ "cmd": "options", "session": files, "limit": 1 }));
+  assert_eq!(options["count"], 2);
+  assert_eq!(options["options"].as_array().unwrap().len(), 1);
+
+  // changing the query erases back to the shared part and types the rest
+  ok(&mut client, json!({ "cmd": "query", "session": files, "query": "lib" }));
+  let options = ok(&mut client, json!({ "cmd": "options", "session": files }));
+  assert_eq!(lines(&options), ["src/lib.rs"]);
+  assert_eq!(options["options"][0]["positions"], json!([4, 5, 6]));
+  assert_eq!(options["options"][0]["display"], "s/-l--i--b-.rs");
+  assert_eq!(options["options"][0]["id"], 1);
+
+  // inserts and removes show up in the open sessions on their next query
+  assert_eq!(ok(&mut client, json!({ "cmd": "insert", "trie": "files", "lines": ["lib/mod.rs", "src/lib.rs"] }))["ids"], json!([3, 1]));
+  assert_eq!(ok(&mut client, json!({ "cmd": "remove", "trie": "files", "lines": ["src/lib.rs", "missing"] }))["removed"], 1);
+  ok(&mut client, json!({ "cmd": "query", "session": files, "query": "lib" }));
+  assert_eq!(lines(&ok(&mut client, json!({ "cmd": "options", "session": files }))), ["lib/mod.rs"]);
+
+  ok(&mut client, json!({ "cmd": "close", "session": files }));
+  ok(&mut other, json!({ "cmd": "close", "session": words }));
+  let _ = std::fs::remove_file(socket);
+}
+
+#[test]
+fn bad_requests_get_an_error_and_keep_the_connection() {
+  let (socket, mut client) = start("errors");
+
+  for request in [
+    json!({ "trie": "files" }),
+    json!({ "cmd": "explode" }),
+    json!({ "cmd": "open", "trie": "missing" }),
+    json!({ "cmd": "query", "session": 42, "query": "a" }),
+    json!({ "cmd": "load", "trie": "files", "lines": [1, 2] }),
+    json!({ "cmd": "load", "trie": "files", "lines": [] }),
+    json!({ "cmd": "open", "trie": "files", "order": "random" }),
+  ] {
+    let response = client.request(&request).unwrap();
+    if request["lines"] == json!([]) {
+      assert_eq!(response["ok"], true);
+    } else {
+      assert_eq!(response["ok"], false, "{} should have failed", request);
+      assert!(response["error"].is_string());
+    }
+  }
+
+  // the connection is still usable after a line which isn't JSON
+  let mut raw = Client::connect(&socket).unwrap();
+  assert_eq!(raw.request(&json!("not an object")).unwrap()["ok"], false);
+  ok(&mut raw, json!({ "cmd": "open", "trie": "files" }));
+
+  // and after a line which isn't valid UTF-8
+  let mut stream = UnixStream::connect(&socket).unwrap();
+  let mut reader = BufReader::new(stream.try_clone().unwrap());
+  let mut response = String::new();
+  for request in [&b"{\"cmd\": \"load\", \"trie\": \"x\", \"lines\": [\"a\xff\"]}\n"[..], b"{\"cmd\": \"load\", \"trie\": \"x\", \"lines\": [\"a\"]}\n"] {
+    stream.write_all(request).unwrap();
+    response.clear();
+    reader.read_line(&mut response).unwrap();
+    let ok = !request.contains(&0xFF);
+    assert_eq!(serde_json::from_str::<Value>(&response).unwrap()["ok"], ok, "{}", response);
+  }
+  let _ = std::fs::remove_file(socket);
+}
+
+#[test]
+fn sessions_are_closed_with_their_connection() {
+  let (socket, mut client) = start("dropped");
+  ok(&mut client, json!({ "cmd": "load", "trie": "files", "lines": ["a"] }));
+
+  let mut plugin = Client::connect(&socket).unwrap();
+  let session = ok(&mut plugin, json!({ "cmd": "open", "trie": "files" }))["session"].clone();
+  // other connections can use it while it's open
+  ok(&mut client, json!({ "cmd": "query", "session": session, "query": "a" }));
+
+  // a client gone without `close`
+  drop(plugin);
+  for _ in 0..100 {
+    let response = client.request(&json!({ "cmd": "query", "session": session, "query": "a" })).unwrap();
+    if response["ok"] == false {
+      assert_eq!(response["error"], format!("unknown session: {}", session));
+      let _ = std::fs::remove_file(socket);
+      return;
+    }
+    thread::sleep(Duration::from_millis(10));
+  }
+  panic!("session {} outlived its connection", session);
+}
+
+#[test]
+fn load_reads_files_like_stdin() {
+  let (socket, mut client) = start("load");
+  let file = std::env::temp_dir().join(format!("cli_search_load_{}", std::process::id()));
+  std::fs::write(&file, b"one\r\ntw\xffo\r\ntwo").unwrap();
+
+  assert_eq!(ok(&mut client, json!({ "cmd": "load", "trie": "t", "path": file }))["count"], 3);
+  let session = ok(&mut client, json!({ "cmd": "open", "trie": "t", "order": "insertion" }))["session"].clone();
+  ok(&mut client, json!({ "cmd": "query", "session": session, "query": "o" }));
+  let options = ok(&mut client, json!({ "cmd": "options", "session": session }));
+  assert_eq!(lines(&options), ["one", "tw\u{fffd}o", "two"]);
+
+  let _ = std::fs::remove_file(file);
+  let _ = std::fs::remove_file(socket);
+}
+
+#[test]
+fn serve_only_replaces_a_stale_socket() {
+  let (socket, mut client) = start("live");
+  // a second daemon leaves the first one its socket
+  let err = Arc::new(Daemon::new()).serve(&socket).unwrap_err();
+  assert_eq!(err.kind(), std::io::ErrorKind::AddrInUse);
+  ok(&mut client, json!({ "cmd": "load", "trie": "files", "lines": ["a"] }));
+  let _ = std::fs::remove_file(socket);
+
+  let file = std::env::temp_dir().join(format!("cli_search_file_{}", std::process::id()));
+  std::fs::write(&file, "keep me").unwrap();
+  assert!(Arc::new(Daemon::new()).serve(&file).is_err());
+  assert_eq!(std::fs::read_to_string(&file).unwrap(), "keep me");
+  std::fs::remove_file(&file).unwrap();
+
+  // a socket nobody listens on any more is taken over
+  let stale = std::env::temp_dir().join(format!("cli_search_stale_{}.sock", std::process::id()));
+  drop(std::os::unix::net::UnixListener::bind(&stale).unwrap());
+  thread::spawn({
+    let stale = stale.clone();
+    move || Arc::new(Daemon::new()).serve(stale)
+  });
+  for _ in 0..100 {
+    if Client::connect(&stale).is_ok() {
+      let _ = std::fs::remove_file(stale);
+      return;
+    }
+    thread::sleep(Duration::from_millis(10));
+  }
+  panic!("the daemon didn't replace the stale socket {}", stale.display());
+}
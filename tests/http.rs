@@ -0,0 +1,93 @@
+use std::{io::{Read, Write}, net::{SocketAddr, TcpStream}, sync::Arc, thread};
+
+use serde_json::{json, Value};
+
+use cli_search::http::{Api, HttpServer};
+use cli_search::{PathMode, SearchConfig, SharedTrie};
+
+fn start(lines: &[&str], config: SearchConfig) -> SocketAddr {
+  let trie = Arc::new(SharedTrie::new());
+  trie.extend(lines);
+  let server = HttpServer::bind(Api::new(trie).with_config(config), "127.0.0.1:0").unwrap();
+  let addr = server.local_addr().unwrap();
+  thread::spawn(move || server.run(4));
+  addr
+}
+
+// the status and JSON body of one request, on a connection of its own
+fn request(addr: SocketAddr, method: &str, url: &str, body: &str) -> (u16, Value) {
+  let mut stream = TcpStream::connect(addr).unwrap();
+  write!(stream, "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}", method, url, body.len(), body).unwrap();
+
+  let mut response = String::new();
+  stream.read_to_string(&mut response).unwrap();
+  let status = response.split(' ').nth(1).unwrap().parse().unwrap();
+  let (_, body) = response.split_once("\r\n\r\n").unwrap();
+  (status, serde_json::from_str(body).unwrap())
+}
+
+fn lines(response: &Value) -> Vec<&str> {
+  response["options"].as_array().unwrap().iter().map(|o| o["line"].as_str().unwrap()).collect()
+}
+
+#[test]
+fn search_insert_remove_and_stats() {
+  let addr = start(&["src/main.rs", "src/lib.rs", "README.md"], SearchConfig::default().with_path(PathMode::default()));
+
+  let (status, response) = request(addr, "GET", "/search?q=lib", "");
+  assert_eq!(status, 200);
+  assert_eq!(lines(&response), ["src/lib.rs"]);
+  assert_eq!(response["options"][0]["positions"], json!([4, 5, 6]));
+  assert_eq!(response["options"][0]["display"], "s/-l--i--b-.rs");
+
+  let (_, response) = request(addr, "GET", "/search?q=rs&limit=1", "");
+  assert_eq!(response["count"], 2);
+  assert_eq!(response["options"].as_array().unwrap().len(), 1);
+
+  let (status, response) = request(addr, "POST", "/insert", r#"{"lines": ["my lib/mod.rs"]}"#);
+  assert_eq!(status, 200);
+  assert_eq!(response["ids"], json!([3]));
+  // the query string is decoded, `+` and `%20` are spaces
+  assert_eq!(lines(&request(addr, "GET", "/search?q=y+l%69", "").1), ["my lib/mod.rs"]);
+
+  assert_eq!(request(addr, "POST", "/remove", r#"{"lines": ["src/lib.rs", "missing"]}"#).1["removed"], 1);
+  assert_eq!(lines(&request(addr, "GET", "/search?q=lib", "").1), ["my lib/mod.rs"]);
+  assert_eq!(request(addr, "GET", "/stats", "").1["lines"], 3);
+}
+
+#[test]
+fn bad_requests_get_an_error_status() {
+  let addr = start(&["alpha"], SearchConfig::default());
+
+  for (method, url, body, expected) in [
+    ("GET", "/search", "", 400),
+    ("GET", "/search?q=a&limit=many", "", 400),
+    ("POST", "/insert", "alpha", 400),
+    ("POST", "/insert", r#"{"lines": [1]}"#, 400),
+    ("GET", "/insert", "", 405),
+    ("GET", "/nowhere", "", 404),
+  ] {
+    let (status, response) = request(addr, method, url, body);
+    assert_eq!(status, expected, "{} {}", method, url);
+    assert!(response["error"].is_string());
+  }
+}
+
+#[test]
+fn concurrent_searches_and_inserts() {
+  let addr = start(&[], SearchConfig::default());
+
+  // every search sees a consistent snapshot: all of a client's lines inserted so far, at least
+  let clients: Vec<_> = (0..8).map(|client| thread::spawn(move || {
+    for i in 0..20 {
+      let line = format!("client{}_{}", client, i);
+      request(addr, "POST", "/insert", &json!({ "lines": [line] }).to_string());
+      let (status, response) = request(addr, "GET", &format!("/search?q=client{}_&limit=100", client), "");
+      assert_eq!(status, 200);
+      assert!(response["count"].as_u64().unwrap() > i);
+    }
+  })).collect();
+  clients.into_iter().for_each(|client| client.join().unwrap());
+
+  assert_eq!(request(addr, "GET", "/stats", "").1["lines"], 160);
+}
@@ -0,0 +1,783 @@
+use std::{collections::{BTreeSet, HashMap}, sync::Arc, thread};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use cli_search::corpus::{Corpus, CorpusConfig, LengthDist, MAX_LENGTH};
+use cli_search::{
+  score_positions, Alignment, Case, Engine, Error, Highlight, Match, Order, PathMode, SearchConfig, SearchEngine, SearchTrie, SharedTrie, Symbol,
+  TPEngine
+};
+
+const ALPHABET: [char; 7] = ['a', 'b', 'c', 'A', 'B', '_', '/'];
+
+// a line and the positions of its queried symbols
+type Found = (String, Vec<usize>);
+
+fn found(m: &Match) -> Found {
+  (m.line(), m.positions())
+}
+
+fn engines<'a>(trie: &'a SearchTrie, config: &SearchConfig) -> Vec<(String, Box<dyn SearchEngine + 'a>)> {
+  let mut engines: Vec<(String, Box<dyn SearchEngine + 'a>)> = vec![
+    ("Engine".to_owned(), Box::new(trie.engine().with_config(config.clone()))),
+    ("TEngine".to_owned(), Box::new(trie.t_engine().with_config(config.clone()))),
+  ];
+  for threads in [1, 2, 4] {
+    engines.push((format!("TPEngine({})", threads), Box::new(trie.tp_engine(threads).with_config(config.clone()))));
+  }
+  engines
+}
+
+fn word_start(line: &[char], i: usize) -> bool {
+  line[i].is_word_start(if i > 0 { Some(&line[i - 1]) } else { None })
+}
+
+// the first occurrence of each queried symbol in turn, checking the line symbol by symbol
+// whether the queried symbol `q` can be placed on `line[i]`
+fn placeable(line: &[char], i: usize, q: &char, config: &SearchConfig) -> bool {
+  let matched = match config.case {
+    Case::Sensitive => line[i] == *q,
+    Case::Smart => line[i].is_matched_by(q)
+  };
+  matched && (!config.acronym || word_start(line, i))
+}
+
+fn greedy(line: &[char], query: &[char], config: &SearchConfig) -> Option<Vec<usize>> {
+  let mut positions = Vec::new();
+  let mut start = 0;
+
+  for q in query {
+    let i = (start..line.len()).find(|&i| placeable(line, i, q, config))?;
+    positions.push(i);
+    start = i + 1;
+  }
+
+  Some(positions)
+}
+
+fn oracle(lines: &BTreeSet<String>, query: &[char], config: &SearchConfig) -> BTreeSet<Found> {
+  lines.iter()
+    .filter_map(|line| {
+      let symbols: Vec<char> = line.chars().collect();
+      greedy(&symbols, query, config).map(|positions| (line.clone(), positions))
+    })
+    .collect()
+}
+
+// a random sequence of keystrokes, each one a queried symbol or a backspace (`None`)
+fn keystrokes(rng: &mut StdRng, len: usize) -> Vec<Option<char>> {
+  (0..len).map(|_| if rng.random_bool(0.25) { None } else { Some(ALPHABET[rng.random_range(0..ALPHABET.len())]) }).collect()
+}
+
+fn check_engines(trie: &SearchTrie, lines: &BTreeSet<String>, keys: &[Option<char>], config: &SearchConfig) {
+  for (name, mut engine) in engines(trie, config) {
+    let mut query = Vec::new();
+
+    for key in keys {
+      match key {
+        Some(c) => {
+          engine.query(*c);
+          query.push(*c);
+        }
+        None => {
+          engine.backspace();
+          query.pop();
+        }
+      }
+
+      let expected = oracle(lines, &query, config);
+      let matches = engine.matches();
+      let actual: BTreeSet<Found> = matches.iter().map(found).collect();
+
+      assert_eq!(matches.len(), actual.len(), "{} reported a line twice for {:?}", name, query);
+      assert!(matches.iter().all(|m| trie.get(m.id()) == Some(found(m).0)), "{} gave a line the wrong id", name);
+      assert_eq!(actual, expected, "{} differs from the oracle for {:?}", name, query);
+      assert_eq!(engine.match_count(), expected.len(), "{} miscounted {:?}", name, query);
+      // ranked matches are filtered on the next keystroke instead of walking the trie, when there are few enough
+      engine.ranked();
+    }
+  }
+}
+
+fn random_lines(seed: u64, count: usize) -> Vec<String> {
+  Corpus::new(CorpusConfig {
+    alphabet: ALPHABET.to_vec(),
+    count,
+    length: LengthDist::Uniform { min: 0, max: 12 },
+    shared_prefix: 0.6,
+    seed,
+  }).collect()
+}
+
+#[test]
+fn engines_match_oracle() {
+  for seed in 0..8 {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut trie: SearchTrie = SearchTrie::new();
+    let mut lines = BTreeSet::new();
+
+    for line in random_lines(seed, 200) {
+      trie.insert(&line);
+      lines.insert(line);
+    }
+
+    for (acronym, case) in [(false, Case::Sensitive), (true, Case::Sensitive), (false, Case::Smart), (true, Case::Smart)] {
+      for max_filtered in [0, usize::MAX] {
+        let config = SearchConfig::default().with_acronym(acronym).with_case(case).with_alignment(Alignment::Greedy).with_max_filtered(max_filtered);
+        check_engines(&trie, &lines, &keystrokes(&mut rng, 12), &config);
+      }
+    }
+  }
+}
+
+#[test]
+fn engines_match_oracle_after_inserts_and_removes() {
+  for seed in 0..8 {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pool = random_lines(seed, 60);
+    let mut trie: SearchTrie = SearchTrie::new();
+    let mut lines = BTreeSet::new();
+
+    for _ in 0..10 {
+      for _ in 0..20 {
+        let line = &pool[rng.random_range(0..pool.len())];
+        if rng.random_bool(0.6) {
+          trie.insert(line);
+          lines.insert(line.clone());
+        } else {
+          trie.remove(line);
+          lines.remove(line);
+        }
+      }
+
+      assert_eq!(trie.len(), lines.len());
+      let config = SearchConfig::default().with_alignment(Alignment::Greedy);
+      check_engines(&trie, &lines, &keystrokes(&mut rng, 8), &config);
+    }
+  }
+}
+
+// the best score of every way of placing the query on the line, checked by trying them all
+fn best_score(line: &[char], query: &[char], config: &SearchConfig) -> Option<i64> {
+  fn place(line: &[char], query: &[char], config: &SearchConfig, start: usize, positions: &mut Vec<usize>, best: &mut Option<i64>) {
+    let j = positions.len();
+    if j == query.len() {
+      let score = score_positions(line, positions, config);
+      *best = Some(best.map_or(score, |b| b.max(score)));
+      return;
+    }
+
+    for i in start..line.len() {
+      if placeable(line, i, &query[j], config) {
+        positions.push(i);
+        place(line, query, config, i + 1, positions, best);
+        positions.pop();
+      }
+    }
+  }
+
+  let mut best = None;
+  place(line, query, config, 0, &mut Vec::new(), &mut best);
+  best
+}
+
+#[test]
+fn optimal_alignment_has_the_best_score() {
+  for seed in 0..8 {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut trie: SearchTrie = SearchTrie::new();
+    let mut lines = BTreeSet::new();
+
+    for line in random_lines(seed, 100) {
+      trie.insert(&line);
+      lines.insert(line);
+    }
+
+    let query: Vec<char> = (0..rng.random_range(1..4)).map(|_| ALPHABET[rng.random_range(0..ALPHABET.len())]).collect();
+    for config in [SearchConfig::default(), SearchConfig::default().with_acronym(true), SearchConfig::default().with_case(Case::Smart)] {
+      let expected: BTreeSet<String> = oracle(&lines, &query, &config).into_iter().map(|(line, _)| line).collect();
+
+      for (name, mut engine) in engines(&trie, &config) {
+        query.iter().for_each(|c| engine.query(*c));
+
+        let matches = engine.matches();
+        assert_eq!(matches.iter().map(|m| found(m).0).collect::<BTreeSet<_>>(), expected, "{} differs from the oracle for {:?}", name, query);
+
+        for m in &matches {
+          let (line, positions) = found(m);
+          let symbols: Vec<char> = line.chars().collect();
+
+          assert_eq!(positions.len(), query.len());
+          assert!(positions.iter().zip(&query).all(|(i, q)| placeable(&symbols, *i, q, &config)), "{} misplaced {:?} on {}", name, query, line);
+          assert_eq!(Some(score_positions(&symbols, &positions, &config)), best_score(&symbols, &query, &config), "{} on {}", name, line);
+        }
+      }
+    }
+  }
+}
+
+#[test]
+fn options_order_is_the_same_for_every_engine() {
+  let lines = random_lines(3, 300);
+  let mut trie: SearchTrie = SearchTrie::new();
+  trie.extend(&lines);
+
+  let mut inserted = Vec::new();
+  lines.iter().for_each(|line| if !inserted.contains(line) { inserted.push(line.clone()) });
+
+  for order in [Order::Score, Order::Lexicographic, Order::Insertion, Order::Length] {
+    let config = SearchConfig::default().with_order(order).with_alignment(Alignment::Greedy);
+    let query = ['a', 'b'];
+
+    let mut expected: Vec<String> = oracle(&inserted.iter().cloned().collect(), &query, &config).into_iter().map(|(line, _)| line).collect();
+    match order {
+      Order::Lexicographic => expected.sort(),
+      Order::Insertion => expected.sort_by_key(|line| inserted.iter().position(|l| l == line)),
+      Order::Length => expected.sort_by(|a, b| a.chars().count().cmp(&b.chars().count()).then_with(|| a.cmp(b))),
+      Order::Score => ()
+    }
+
+    let mut first: Option<Vec<String>> = None;
+    for _ in 0..3 {
+      for (name, mut engine) in engines(&trie, &config) {
+        query.iter().for_each(|c| engine.query(*c));
+        let options = engine.options();
+
+        if order != Order::Score {
+          let ranked: Vec<String> = options.iter().map(|o| o.replace('-', "")).collect();
+          assert_eq!(ranked, expected, "{} listed {:?} out of order", name, order);
+        }
+        match &first {
+          Some(first) => assert_eq!(&options, first, "{} listed {:?} in another order", name, order),
+          None => first = Some(options)
+        }
+      }
+    }
+  }
+}
+
+#[test]
+fn ranked_matches_are_kept_across_backspace() {
+  let mut trie: SearchTrie = SearchTrie::new();
+  trie.extend(random_lines(5, 300));
+  let config = SearchConfig::default();
+
+  for (name, mut engine) in engines(&trie, &config) {
+    let mut levels = Vec::new();
+    for c in ['a', 'b', 'c'] {
+      engine.query(c);
+      levels.push(engine.ranked());
+    }
+
+    // erasing brings back the levels below, typing the same symbols again brings back the erased ones
+    for _ in 0..2 {
+      engine.backspace();
+      assert!(Arc::ptr_eq(&engine.ranked(), &levels[1]), "{} ranked the matches again after a backspace", name);
+      engine.backspace();
+      engine.query('b');
+      assert!(Arc::ptr_eq(&engine.ranked(), &levels[1]), "{} ranked the matches again after retyping", name);
+      engine.query('c');
+      assert!(Arc::ptr_eq(&engine.ranked(), &levels[2]), "{} ranked the matches again after retyping", name);
+    }
+
+    // another symbol starts a new level, which must match a fresh engine
+    engine.backspace();
+    engine.query('a');
+    let mut fresh = trie.engine().with_config(config.clone());
+    "aba".chars().for_each(|c| fresh.query(c));
+    assert_eq!(engine.options(), fresh.options(), "{} kept a stale level", name);
+  }
+}
+
+#[test]
+fn filtered_options_match_walked_ones() {
+  let mut trie: SearchTrie = SearchTrie::new();
+  trie.extend(random_lines(7, 400));
+  let mut rng = StdRng::seed_from_u64(7);
+
+  let configs = [
+    SearchConfig::default(),
+    SearchConfig::default().with_acronym(true),
+    SearchConfig::default().with_path(PathMode::default().with_require_basename(true).with_abbreviate(false)),
+    SearchConfig::default().with_path(PathMode::default()).with_order(Order::Length),
+  ];
+
+  for config in configs {
+    let keys = keystrokes(&mut rng, 16);
+    let walked = config.clone().with_max_filtered(0);
+    let mut expected = trie.engine().with_config(walked);
+
+    for (name, mut engine) in engines(&trie, &config.clone().with_max_filtered(usize::MAX)) {
+      for key in &keys {
+        match key {
+          Some(c) => {
+            engine.query(*c);
+            expected.query(*c);
+          }
+          None => {
+            engine.backspace();
+            expected.backspace();
+          }
+        }
+
+        assert_eq!(engine.options(), expected.options(), "{} filtered other options than the trie gives", name);
+        assert_eq!(engine.match_count(), expected.match_count(), "{} miscounted the filtered matches", name);
+      }
+      keys.iter().for_each(|_| expected.backspace());
+    }
+  }
+}
+
+#[test]
+fn snapshots_are_unaffected_by_later_changes() {
+  let mut trie: SearchTrie = SearchTrie::new();
+  trie.extend(["abc", "abd", "xyz"]);
+  let snapshot = trie.snapshot();
+
+  trie.insert("abe");
+  trie.remove("abc");
+  trie.remove("xyz");
+
+  let mut before = Engine::new(snapshot);
+  let mut after = trie.engine();
+  "ab".chars().for_each(|c| {
+    before.query(c);
+    after.query(c);
+  });
+
+  assert_eq!(before.options(), ["-a--b-c", "-a--b-d"]);
+  assert_eq!(after.options(), ["-a--b-d", "-a--b-e"]);
+}
+
+#[test]
+fn live_engines_pick_up_streamed_lines() {
+  let lines = random_lines(11, 400);
+  let trie = Arc::new(SharedTrie::new());
+  let config = SearchConfig::default().with_alignment(Alignment::Greedy);
+  let mut engines: Vec<(&str, Box<dyn SearchEngine + Send>)> = vec![
+    ("Engine", Box::new(trie.engine().with_config(config.clone()))),
+    ("TEngine", Box::new(trie.t_engine().with_config(config.clone()))),
+    ("TPEngine", Box::new(trie.tp_engine(2).with_config(config.clone()))),
+  ];
+
+  let writer = {
+    let (trie, lines) = (trie.clone(), lines.clone());
+    thread::spawn(move || lines.iter().enumerate().for_each(|(i, line)| {
+      trie.insert(line);
+      // some lines are taken out again, a live engine has to drop them
+      if i % 5 == 0 {
+        trie.remove(&lines[i / 2]);
+      }
+    }))
+  };
+
+  let mut rng = StdRng::seed_from_u64(11);
+  let keys = keystrokes(&mut rng, 40);
+  for key in &keys {
+    for (_, engine) in engines.iter_mut() {
+      match key {
+        Some(c) => engine.query(*c),
+        None => engine.backspace()
+      }
+      engine.options();
+    }
+  }
+  writer.join().unwrap();
+
+  let lines: BTreeSet<String> = trie.read().iter().collect();
+  let expected = oracle(&lines, &['a', 'b'], &SearchConfig::default());
+  assert!(!expected.is_empty());
+
+  for (name, engine) in engines.iter_mut() {
+    // the next keystrokes move the engine to the final trie
+    keys.iter().for_each(|_| engine.backspace());
+    engine.query('a');
+    engine.query('b');
+
+    let actual: BTreeSet<Found> = engine.matches().iter().map(found).collect();
+    assert_eq!(actual, expected, "{} missed lines inserted or removed while it searched", name);
+    assert_eq!(engine.match_count(), expected.len(), "{} miscounted the streamed lines", name);
+  }
+}
+
+#[test]
+fn live_engines_keep_their_levels_across_changes() {
+  let pool = random_lines(13, 600);
+  let mut rng = StdRng::seed_from_u64(13);
+
+  let configs = [
+    SearchConfig::default(),
+    SearchConfig::default().with_acronym(true).with_alignment(Alignment::Greedy),
+    SearchConfig::default().with_path(PathMode::default().with_require_basename(true)),
+    SearchConfig::default().with_max_filtered(0),
+  ];
+
+  for config in configs {
+    let trie = Arc::new(SharedTrie::new());
+    trie.extend(&pool[..300]);
+    let mut engines: Vec<(&str, Box<dyn SearchEngine>)> = vec![
+      ("Engine", Box::new(trie.engine().with_config(config.clone()))),
+      ("TEngine", Box::new(trie.t_engine().with_config(config.clone()))),
+      ("TPEngine", Box::new(trie.tp_engine(2).with_config(config.clone()))),
+    ];
+    let mut query = Vec::new();
+
+    for key in keystrokes(&mut rng, 30) {
+      // a few lines change between the keystrokes, too few to walk the trie again
+      for _ in 0..rng.random_range(0..4) {
+        let line = &pool[rng.random_range(0..pool.len())];
+        if rng.random_bool(0.5) {
+          trie.insert(line);
+        } else {
+          trie.remove(line);
+        }
+      }
+
+      match key {
+        Some(c) => query.push(c),
+        None => {
+          query.pop();
+        }
+      }
+      let mut expected = trie.read().owned_engine().with_config(config.clone());
+      query.iter().for_each(|c| expected.query(*c));
+
+      for (name, engine) in engines.iter_mut() {
+        match key {
+          Some(c) => engine.query(c),
+          None => engine.backspace()
+        }
+        assert_eq!(engine.options(), expected.options(), "{} missed a changed line", name);
+        assert_eq!(engine.match_count(), expected.match_count(), "{} miscounted the changed lines", name);
+      }
+    }
+  }
+
+  // a change which doesn't reach the query leaves its ranked matches alone
+  let trie = Arc::new(SharedTrie::new());
+  trie.extend(["abc", "abd", "xyz"]);
+  let mut engine = trie.engine();
+  "ab".chars().for_each(|c| engine.query(c));
+  let ranked = engine.ranked();
+  trie.insert("xxy");
+  assert!(engine.refresh().unwrap());
+  assert!(Arc::ptr_eq(&engine.ranked(), &ranked));
+  trie.insert("abe");
+  engine.refresh().unwrap();
+  assert_eq!(engine.options(), ["-a--b-c", "-a--b-d", "-a--b-e"]);
+}
+
+#[test]
+fn owned_engines_outlive_the_trie() {
+  fn assert_send_static<E: Send + 'static>(engine: E) -> E {
+    engine
+  }
+
+  let lines = random_lines(13, 200);
+  let mut trie: SearchTrie = SearchTrie::new();
+  trie.extend(&lines);
+
+  let mut expected = trie.engine();
+  "ab".chars().for_each(|c| expected.query(c));
+  let expected = expected.options();
+
+  let mut sessions: HashMap<&str, Box<dyn SearchEngine + Send>> = HashMap::new();
+  sessions.insert("engine", Box::new(assert_send_static(trie.owned_engine())));
+  sessions.insert("t_engine", Box::new(assert_send_static(trie.owned_t_engine())));
+  sessions.insert("tp_engine", Box::new(assert_send_static(trie.owned_tp_engine(2))));
+  // the engines keep the lines they were created on
+  trie.extend(["ab_new", "new_ab"]);
+  drop(trie);
+
+  thread::spawn(move || {
+    for (name, engine) in sessions.iter_mut() {
+      "ab".chars().for_each(|c| engine.query(c));
+      assert_eq!(engine.options(), expected, "{} lost track of its snapshot", name);
+    }
+  }).join().unwrap();
+}
+
+// the example at the top of lib.rs
+#[test]
+fn crate_example() {
+  let trie: SearchTrie = ["src/main.rs", "src/lib.rs"].into_iter().collect();
+  let mut engine = trie.engine().with_config(SearchConfig::default().with_path(PathMode::default()));
+  "lib".chars().for_each(|c| engine.query(c));
+  assert_eq!(engine.options(), ["s/-l--i--b-.rs"]);
+}
+
+#[test]
+fn require_basename_places_the_last_symbol_in_the_basename() {
+  let trie: SearchTrie = ["ab/xb", "a/a.rs", "ab/x"].into_iter().collect();
+  let path = PathMode::default().with_require_basename(true).with_abbreviate(false);
+
+  for alignment in [Alignment::Greedy, Alignment::Optimal] {
+    for max_filtered in [0, usize::MAX] {
+      let config = SearchConfig::default().with_path(path.clone()).with_alignment(alignment).with_max_filtered(max_filtered);
+      for (name, mut engine) in engines(&trie, &config) {
+        engine.query('a');
+        let matched: Vec<Found> = engine.ranked().iter().map(found).collect();
+        assert_eq!(matched, [("a/a.rs".to_owned(), vec![2])], "{} with {:?}", name, alignment);
+
+        engine.query('b');
+        let matched: Vec<Found> = engine.ranked().iter().map(found).collect();
+        assert_eq!(matched, [("ab/xb".to_owned(), vec![0, 4])], "{} with {:?}", name, alignment);
+        assert_eq!(engine.match_count(), 1, "{} with {:?}", name, alignment);
+        assert_eq!(engine.options(), ["-a-b/x-b-"], "{} with {:?}", name, alignment);
+      }
+    }
+  }
+}
+
+#[test]
+fn long_lines_keep_the_first_occurrences() {
+  let positions = |gap: usize, alignment: Alignment| {
+    let trie: SearchTrie = [format!("a{}ab", "x".repeat(gap))].into_iter().collect();
+    let mut engine = trie.engine().with_config(SearchConfig::default().with_alignment(alignment));
+    "ab".chars().for_each(|c| engine.query(c));
+    engine.matches()[0].positions()
+  };
+
+  assert_eq!(positions(10, Alignment::Optimal), [11, 12]);
+  // too long to align, placed as the traversal found them
+  assert_eq!(positions(1 << 18, Alignment::Optimal), positions(1 << 18, Alignment::Greedy));
+  assert_eq!(positions(1 << 18, Alignment::Optimal), [0, (1 << 18) + 2]);
+}
+
+#[test]
+fn highlight_markers() {
+  let trie: SearchTrie = ["src/lib.rs"].into_iter().collect();
+  for (highlight, expected) in [
+    (Highlight::new("[", "]"), "s/[l][i][b].rs"),
+    (Highlight::new("", ""), "s/lib.rs"),
+    (Highlight::ansi(), "s/\x1b[1;31ml\x1b[0m\x1b[1;31mi\x1b[0m\x1b[1;31mb\x1b[0m.rs"),
+  ] {
+    let config = SearchConfig::default().with_path(PathMode::default()).with_highlight(highlight);
+    let mut engine = trie.engine().with_config(config);
+    "lib".chars().for_each(|c| engine.query(c));
+    assert_eq!(engine.options(), [expected]);
+  }
+}
+
+#[test]
+fn bytes_only_start_words_on_ascii() {
+  let line = u8::split("xé_Ab");
+  let starts: Vec<usize> = (0..line.len()).filter(|&i| line[i].is_word_start(i.checked_sub(1).map(|p| &line[p]))).collect();
+  // x, A after the separator, never the bytes of é
+  assert_eq!(starts, [0, 4]);
+
+  let trie: SearchTrie<u8> = ["aé", "aXb"].into_iter().collect();
+  let mut engine = trie.engine().with_config(SearchConfig::default().with_acronym(true));
+  engine.query(b'a');
+  engine.query(0xC3);
+  assert_eq!(engine.match_count(), 0);
+}
+
+#[test]
+fn line_ids_are_stable() {
+  let mut trie: SearchTrie = SearchTrie::new();
+  let ids: Vec<_> = ["line 1", "line 10", "line 2", "not a line"].iter().map(|line| trie.insert(line)).collect();
+
+  assert!(ids.windows(2).all(|w| w[0] < w[1]));
+  assert_eq!(trie.insert("line 10"), ids[1]);
+  assert_eq!(trie.get(ids[2]).as_deref(), Some("line 2"));
+
+  assert_eq!(trie.remove_by_id(ids[0]).as_deref(), Some("line 1"));
+  assert_eq!(trie.get(ids[0]), None);
+  assert_eq!(trie.remove_by_id(ids[0]), None);
+  assert_eq!(trie.get(ids[1]).as_deref(), Some("line 10"));
+  assert_eq!(trie.len(), 3);
+
+  let again = trie.insert("line 1");
+  assert!(again > ids[3]);
+  assert_eq!(trie.remove("line 2"), Some(ids[2]));
+  assert_eq!(trie.remove("line 2"), None);
+}
+
+#[test]
+fn list_includes_lines_which_are_prefixes_of_others() {
+  let mut trie: SearchTrie = SearchTrie::new();
+  trie.extend(["line 1", "line 10", "line 2", "not a line"]);
+
+  assert_eq!(trie.list(), ["line 1", "line 10", "line 2", "not a line"]);
+  assert_eq!(trie.iter_prefix("line 1").collect::<Vec<_>>(), ["line 1", "line 10"]);
+  assert_eq!(trie.iter_prefix("nope").count(), 0);
+}
+
+#[test]
+fn iterators_are_lexicographic() {
+  for seed in 0..4 {
+    let lines: BTreeSet<String> = random_lines(seed, 300).into_iter().collect();
+    let mut trie: SearchTrie = SearchTrie::new();
+    trie.extend(&lines);
+
+    assert_eq!(trie.iter().collect::<Vec<_>>(), lines.iter().cloned().collect::<Vec<_>>());
+    for prefix in ["a", "aB", "_/", ""] {
+      let expected: Vec<String> = lines.iter().filter(|l| l.starts_with(prefix)).cloned().collect();
+      assert_eq!(trie.iter_prefix(prefix).collect::<Vec<_>>(), expected, "prefix {:?}", prefix);
+    }
+  }
+}
+
+#[test]
+fn exact_and_prefix_lookups() {
+  let mut trie: SearchTrie = SearchTrie::new();
+  assert_eq!(trie.complete(""), None);
+  assert_eq!(trie.longest_common_prefix(), "");
+
+  trie.extend(["src/search_trie/engine.rs", "src/search_trie/mod.rs", "src/main.rs", "src/search"]);
+
+  assert!(trie.contains("src/search"));
+  assert!(!trie.contains("src/sea"));
+  assert!(trie.starts_with("src/sea"));
+  assert!(!trie.starts_with("docs"));
+
+  assert_eq!(trie.count_prefix(""), 4);
+  assert_eq!(trie.count_prefix("src/search"), 3);
+  assert_eq!(trie.count_prefix("src/search_trie/"), 2);
+  assert_eq!(trie.count_prefix("src/x"), 0);
+
+  assert_eq!(trie.longest_common_prefix(), "src/");
+  assert_eq!(trie.complete("src/s").as_deref(), Some("src/search"));
+  assert_eq!(trie.complete("src/search_").as_deref(), Some("src/search_trie/"));
+  assert_eq!(trie.complete("src/m").as_deref(), Some("src/main.rs"));
+  assert_eq!(trie.complete("lib"), None);
+
+  trie.remove("src/main.rs");
+  assert_eq!(trie.longest_common_prefix(), "src/search");
+}
+
+// a symbol which panics when the engines look at a `!` of a line, to fail the workers
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Fragile(char);
+
+impl Symbol for Fragile {
+  fn split(line: &str) -> Vec<Self> {
+    line.chars().map(Fragile).collect()
+  }
+
+  fn render(&self, out: &mut String) {
+    out.push(self.0);
+  }
+
+  fn is_word_start(&self, prev: Option<&Self>) -> bool {
+    assert!(self.0 != '!', "looked at a fragile symbol");
+    prev.is_none()
+  }
+
+  fn is_matched_by(&self, query: &Self) -> bool {
+    assert!(self.0 != '!', "looked at a fragile symbol");
+    self == query
+  }
+}
+
+#[test]
+fn worker_panics_are_errors() {
+  let trie: SearchTrie<Fragile> = ["ab!c", "abd", "x"].into_iter().collect();
+  let engines = |config: SearchConfig| -> Vec<(&str, Box<dyn SearchEngine<Fragile> + '_>)> {
+    vec![
+      ("TEngine", Box::new(trie.t_engine().with_config(config.clone()))),
+      ("TPEngine(1)", Box::new(trie.tp_engine(1).with_config(config.clone()))),
+      ("TPEngine(4)", Box::new(trie.tp_engine(4).with_config(config))),
+    ]
+  };
+
+  for (name, mut engine) in engines(SearchConfig::default()) {
+    engine.try_query(Fragile('a')).unwrap();
+    // looking for `c` below `a` walks over the `!`
+    let error = engine.try_query(Fragile('c')).err().unwrap_or_else(|| panic!("{} didn't fail", name));
+    assert!(matches!(&error, Error::Worker(reason) if reason == "looked at a fragile symbol"), "{}: {}", name, error);
+
+    // the failed symbol isn't part of the query and the threads are still there
+    assert_eq!(engine.match_count(), 2, "{}", name);
+    engine.try_query(Fragile('b')).unwrap();
+    assert_eq!(engine.match_count(), 2, "{}", name);
+
+    // the optimal alignment of `ab!c` looks at every symbol of the line
+    assert!(matches!(engine.try_ranked(), Err(Error::Worker(_))), "{}", name);
+    // nothing is cached from the failure
+    assert!(matches!(engine.try_options(), Err(Error::Worker(_))), "{}", name);
+  }
+
+  // the greedy alignment ranks the matches of `a` without looking past it, the next keystroke filters them
+  for (name, mut engine) in engines(SearchConfig::default().with_alignment(Alignment::Greedy)) {
+    engine.try_query(Fragile('a')).unwrap();
+    assert_eq!(engine.try_ranked().unwrap().len(), 2, "{}", name);
+    let error = engine.try_query(Fragile('c')).err().unwrap_or_else(|| panic!("{} didn't fail", name));
+    assert!(matches!(&error, Error::Worker(reason) if reason == "looked at a fragile symbol"), "{}: {}", name, error);
+    assert_eq!(engine.match_count(), 2, "{}", name);
+    assert_eq!(engine.try_ranked().unwrap().len(), 2, "{}", name);
+  }
+}
+
+#[test]
+fn read_lines_and_typed_errors() {
+  let mut trie: SearchTrie = SearchTrie::new();
+  assert_eq!(trie.read_lines(&b"one\r\n\ntw\xffo\nthree"[..]).unwrap(), 4);
+  assert_eq!(trie.list(), ["", "one", "three", "tw\u{fffd}o"]);
+
+  // the bytes are kept as they are read
+  let mut bytes: SearchTrie<u8> = SearchTrie::new();
+  assert_eq!(bytes.read_lines(&b"tw\xffo\r\none"[..]).unwrap(), 2);
+  let mut engine = bytes.engine();
+  engine.try_query(0xFF).unwrap();
+  assert_eq!(engine.try_options().unwrap(), ["tw-\\xFF-o"]);
+
+  let shared = Arc::new(SharedTrie::new());
+  assert_eq!(shared.read_lines(&b"a\nb\n"[..]).unwrap(), 2);
+  let mut engine = shared.engine();
+  engine.try_query('a').unwrap();
+  assert_eq!(engine.try_options().unwrap(), ["-a-"]);
+
+  let error = TPEngine::try_new(trie.snapshot(), 0).err().unwrap();
+  assert!(matches!(error, Error::InvalidValue { what: "thread count", .. }), "{}", error);
+  assert_eq!(error.to_string(), "invalid thread count: 0");
+
+  let corpus = |config: CorpusConfig| Corpus::try_new(config).err().map(|e| e.to_string());
+  assert_eq!(corpus(CorpusConfig { alphabet: Vec::new(), ..CorpusConfig::default() }).as_deref(), Some("invalid alphabet: "));
+  assert_eq!(corpus(CorpusConfig { shared_prefix: 1.5, ..CorpusConfig::default() }).as_deref(), Some("invalid shared prefix: 1.5"));
+  assert!(corpus(CorpusConfig { length: LengthDist::Fixed(usize::MAX), ..CorpusConfig::default() }).is_some());
+  assert!("1e30~1".parse::<LengthDist>().is_err());
+  assert!("0..18446744073709551615".parse::<LengthDist>().is_err());
+  // a sample far from the mean is clamped
+  let line = Corpus::new(CorpusConfig { count: 1, length: LengthDist::Normal { mean: 1.0, std_dev: 1e7 }, ..CorpusConfig::default() }).next();
+  assert!(line.is_some_and(|line| (1..=MAX_LENGTH).contains(&line.len())));
+}
+
+#[test]
+fn megabyte_long_lines() {
+  const LEN: usize = 1 << 20;
+  let lines = [
+    format!("{}ab", "x".repeat(LEN)),
+    format!("a{}b", "y".repeat(LEN)),
+    format!("{}_{}", "z".repeat(LEN / 2), "c".repeat(LEN / 2)),
+    "short ab".to_owned()
+  ];
+
+  let mut trie: SearchTrie = SearchTrie::new();
+  trie.extend(&lines);
+
+  assert_eq!(trie.iter().map(|line| line.len()).collect::<Vec<_>>(), [LEN + 2, 8, LEN + 2, LEN + 1]);
+  assert_eq!(trie.complete("xxx").map(|line| line.len()), Some(LEN + 2));
+
+  // one engine of each kind, the walks are slow in debug builds
+  let engines: Vec<(&str, Box<dyn SearchEngine>)> = vec![
+    ("Engine", Box::new(trie.engine())),
+    ("TEngine", Box::new(trie.t_engine())),
+    ("TPEngine", Box::new(trie.tp_engine(2)))
+  ];
+
+  for (name, mut engine) in engines {
+    engine.query('a');
+    engine.query('b');
+
+    let matches = engine.matches();
+    assert_eq!(matches.len(), 3, "{}", name);
+    assert_eq!(engine.match_count(), 3, "{}", name);
+    assert!(matches.iter().all(|m| trie.get(m.id()) == Some(found(m).0)), "{}", name);
+    assert_eq!(engine.options().len(), 3, "{}", name);
+  }
+
+  assert!(trie.remove(&lines[1]).is_some());
+  assert_eq!(trie.len(), 3);
+}
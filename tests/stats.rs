@@ -0,0 +1,84 @@
+use std::{alloc::{GlobalAlloc, Layout, System}, cell::Cell, collections::BTreeMap};
+
+use cli_search::corpus::{Corpus, CorpusConfig};
+use cli_search::{SearchTrie, Symbol, Token};
+
+// counts the bytes allocated by each thread, so the tests running alongside don't count
+struct Counting;
+
+thread_local! {
+  static ALLOCATED: Cell<isize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let _ = ALLOCATED.try_with(|a| a.set(a.get() + layout.size() as isize));
+    unsafe { System.alloc(layout) }
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    let _ = ALLOCATED.try_with(|a| a.set(a.get() - layout.size() as isize));
+    unsafe { System.dealloc(ptr, layout) }
+  }
+}
+
+#[global_allocator]
+static COUNTING: Counting = Counting;
+
+// the bytes `build` leaves allocated on this thread with what it built
+fn measure<T>(build: impl FnOnce() -> T) -> (T, usize) {
+  let before = ALLOCATED.with(Cell::get);
+  let built = build();
+  (built, (ALLOCATED.with(Cell::get) - before) as usize)
+}
+
+fn assert_close<S: Symbol>(lines: &[String]) {
+  let (trie, allocated) = measure(|| lines.iter().collect::<SearchTrie<S>>());
+  let estimated = trie.stats().heap_bytes();
+  let error = estimated.abs_diff(allocated) as f64 / allocated as f64;
+  assert!(error < 0.05, "estimated {} bytes for {} allocated ({} lines)", estimated, allocated, lines.len());
+}
+
+#[test]
+fn heap_bytes_are_close_to_the_allocations() {
+  for count in [1, 10, 1000, 20000] {
+    let lines: Vec<String> = Corpus::new(CorpusConfig { count, ..CorpusConfig::default() }).collect();
+    assert_close::<char>(&lines);
+    assert_close::<u8>(&lines);
+
+    let words: Vec<String> = lines.iter().map(|line| line.chars().collect::<Vec<_>>().chunks(4).map(|w| w.iter().collect::<String>()).collect::<Vec<_>>().join(" ")).collect();
+    assert_close::<Token>(&words);
+  }
+}
+
+#[test]
+fn shape() {
+  let mut trie: SearchTrie = ["", "ab", "abc", "abd", "x"].into_iter().collect();
+  let stats = trie.stats();
+
+  // the root, a, b, c, d and x
+  assert_eq!(stats.nodes, 6);
+  assert_eq!(stats.terminals, 5);
+  assert_eq!(stats.max_depth, 3);
+  // the empty line ends at the root
+  assert_eq!(stats.mean_depth, (2 + 3 + 3 + 1) as f64 / 5.0);
+  assert_eq!(stats.branching, BTreeMap::from([(0, 3), (1, 1), (2, 2)]));
+
+  trie.remove("abd");
+  trie.remove("x");
+  let stats = trie.stats();
+  assert_eq!((stats.nodes, stats.terminals, stats.max_depth), (4, 3, 3));
+  assert_eq!(stats.branching, BTreeMap::from([(0, 1), (1, 3)]));
+
+  let empty: SearchTrie = SearchTrie::new();
+  let stats = empty.stats();
+  assert_eq!((stats.nodes, stats.terminals, stats.max_depth, stats.mean_depth), (1, 0, 0, 0.0));
+}
+
+#[test]
+fn megabyte_long_line() {
+  let line = "a".repeat(1 << 20);
+  let trie: SearchTrie = [line.as_str()].into_iter().collect();
+  let stats = trie.stats();
+  assert_eq!((stats.nodes, stats.max_depth), ((1 << 20) + 1, 1 << 20));
+}